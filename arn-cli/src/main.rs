@@ -0,0 +1,342 @@
+#![warn(clippy::all)]
+
+//! The `arn` command-line tool validates, explodes, and sorts Amazon Resource Names (ARNs).
+//!
+//! ARNs are taken from the command line or, if none are given, read from standard input one per line. Invalid ARNs
+//! are reported on standard error with a caret marking the offending characters.
+//!
+//! The exit status is 0 if every ARN is valid, 1 if any ARN is invalid, and 2 on usage or I/O errors.
+
+use {
+    clap::{Parser, Subcommand},
+    scratchstack_arn::{Arn, AwsStrict, ScratchstackRelaxed, ValidationProfile},
+    serde_json::json,
+    std::{
+        io::{self, BufRead, ErrorKind, Write},
+        process::ExitCode,
+        string::FromUtf8Error,
+    },
+};
+
+/// Exit status when every ARN is valid.
+const EXIT_OK: u8 = 0;
+
+/// Exit status when one or more ARNs are invalid.
+const EXIT_INVALID: u8 = 1;
+
+/// Exit status for I/O errors. This is also the status clap uses for usage errors.
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "arn",
+    version,
+    about = "Validate, explode, and sort Amazon Resource Names (ARNs)",
+    after_help = "Exit status: 0 if every ARN is valid, 1 if any ARN is invalid, 2 on usage or I/O errors."
+)]
+struct Cli {
+    /// Apply the rules AWS itself uses instead of the relaxed Scratchstack rules.
+    #[arg(long, global = true)]
+    strict: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check ARNs, printing the valid ones and reporting the invalid ones.
+    Validate {
+        /// Do not print valid ARNs.
+        #[arg(short, long)]
+        quiet: bool,
+
+        /// ARNs to check. If none are given, they are read from standard input, one per line.
+        arns: Vec<String>,
+    },
+
+    /// Print the components of each ARN as a JSON object, one per line.
+    Explode {
+        /// ARNs to explode. If none are given, they are read from standard input, one per line.
+        arns: Vec<String>,
+    },
+
+    /// Print ARNs sorted by partition, service, region, account id, and resource.
+    Sort {
+        /// Print each distinct ARN only once.
+        #[arg(short, long)]
+        unique: bool,
+
+        /// ARNs to sort. If none are given, they are read from standard input, one per line.
+        arns: Vec<String>,
+    },
+}
+
+/// An ARN string along with a description of where it came from, for error messages.
+struct Input {
+    source: String,
+    /// The ARN, or the decoding error if the line read from standard input was not valid UTF-8.
+    arn: Result<String, FromUtf8Error>,
+}
+
+/// Collect the ARNs from the command line or, if none were given, from `stdin`. Blank lines are ignored.
+fn read_inputs<R: BufRead>(args: Vec<String>, mut stdin: R) -> io::Result<Vec<Input>> {
+    if !args.is_empty() {
+        return Ok(args
+            .into_iter()
+            .enumerate()
+            .map(|(i, arn)| Input {
+                source: format!("argument {}", i + 1),
+                arn: Ok(arn),
+            })
+            .collect());
+    }
+
+    // Lines are read as bytes so a line that is not valid UTF-8 is reported as an invalid ARN instead of ending the
+    // read.
+    let mut inputs = Vec::new();
+    for i in 1.. {
+        let mut buf = Vec::new();
+        if stdin.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+
+        let arn = match String::from_utf8(buf) {
+            Ok(line) if line.trim().is_empty() => continue,
+            Ok(line) => Ok(line.trim().to_string()),
+            Err(e) => Err(e),
+        };
+        inputs.push(Input {
+            source: format!("line {i}"),
+            arn,
+        });
+    }
+
+    Ok(inputs)
+}
+
+/// Parse each input, reporting invalid ARNs to `stderr`. Returns the valid ARNs and whether any were invalid.
+fn parse_inputs<E: Write>(
+    inputs: Vec<Input>,
+    profile: &dyn ValidationProfile,
+    stderr: &mut E,
+) -> io::Result<(Vec<Arn>, bool)> {
+    let mut arns = Vec::with_capacity(inputs.len());
+    let mut any_invalid = false;
+
+    for input in inputs {
+        let arn = match input.arn {
+            Ok(arn) => arn,
+            Err(e) => {
+                writeln!(stderr, "{}: Invalid UTF-8: {e}", input.source)?;
+                any_invalid = true;
+                continue;
+            }
+        };
+
+        match Arn::from_str_detailed_with_profile(&arn, profile) {
+            Ok(arn) => arns.push(arn),
+            Err(e) => {
+                writeln!(stderr, "{}: {e:#}", input.source)?;
+                any_invalid = true;
+            }
+        }
+    }
+
+    Ok((arns, any_invalid))
+}
+
+/// Run the command, returning the exit status.
+fn run<R: BufRead, W: Write, E: Write>(cli: Cli, stdin: R, stdout: &mut W, stderr: &mut E) -> io::Result<u8> {
+    let profile: &dyn ValidationProfile = if cli.strict {
+        &AwsStrict
+    } else {
+        &ScratchstackRelaxed
+    };
+
+    let any_invalid = match cli.command {
+        Command::Validate {
+            quiet,
+            arns,
+        } => {
+            let (arns, any_invalid) = parse_inputs(read_inputs(arns, stdin)?, profile, stderr)?;
+            if !quiet {
+                for arn in arns {
+                    writeln!(stdout, "{arn}")?;
+                }
+            }
+            any_invalid
+        }
+
+        Command::Explode {
+            arns,
+        } => {
+            let (arns, any_invalid) = parse_inputs(read_inputs(arns, stdin)?, profile, stderr)?;
+            for arn in arns {
+                let value = json!({
+                    "arn": arn.to_string(),
+                    "partition": arn.partition(),
+                    "service": arn.service(),
+                    "region": arn.region(),
+                    "account_id": arn.account_id(),
+                    "resource": arn.resource(),
+                });
+                writeln!(stdout, "{value}")?;
+            }
+            any_invalid
+        }
+
+        Command::Sort {
+            unique,
+            arns,
+        } => {
+            let (mut arns, any_invalid) = parse_inputs(read_inputs(arns, stdin)?, profile, stderr)?;
+            arns.sort();
+            if unique {
+                arns.dedup();
+            }
+            for arn in arns {
+                writeln!(stdout, "{arn}")?;
+            }
+            any_invalid
+        }
+    };
+
+    stdout.flush()?;
+    Ok(if any_invalid {
+        EXIT_INVALID
+    } else {
+        EXIT_OK
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli, io::stdin().lock(), &mut io::stdout().lock(), &mut io::stderr().lock()) {
+        Ok(status) => ExitCode::from(status),
+        // The reader went away (e.g. piped into `head`); this is not an error.
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => ExitCode::from(EXIT_OK),
+        Err(e) => {
+            eprintln!("arn: {e}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{run, Cli, EXIT_INVALID, EXIT_OK},
+        clap::Parser,
+        pretty_assertions::assert_eq,
+    };
+
+    /// Run the tool with the specified arguments and standard input, returning the exit status, standard output, and
+    /// standard error.
+    fn run_with(args: &[&str], stdin: &str) -> (u8, String, String) {
+        let cli = Cli::try_parse_from(args).unwrap();
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let status = run(cli, stdin.as_bytes(), &mut stdout, &mut stderr).unwrap();
+        (status, String::from_utf8(stdout).unwrap(), String::from_utf8(stderr).unwrap())
+    }
+
+    #[test]
+    fn check_validate() {
+        let (status, stdout, stderr) = run_with(
+            &["arn", "validate", "arn:aws:s3:::bucket", "arn:aws:ec2:us-east-1:123456789012:instance/i-1"],
+            "",
+        );
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(stdout, "arn:aws:s3:::bucket\narn:aws:ec2:us-east-1:123456789012:instance/i-1\n");
+        assert_eq!(stderr, "");
+
+        let (status, stdout, stderr) =
+            run_with(&["arn", "validate"], "arn:aws:s3:::bucket\n\n  arn:aws:ec2:us-east1:123456789012:instance/i-1\n");
+        assert_eq!(status, EXIT_INVALID);
+        assert_eq!(stdout, "arn:aws:s3:::bucket\n");
+        assert_eq!(
+            stderr,
+            concat!(
+                "line 3: Invalid region: \"us-east1\": unexpected character '1' at byte 19\n",
+                "  arn:aws:ec2:us-east1:123456789012:instance/i-1\n",
+                "                     ^\n",
+            )
+        );
+
+        let (status, stdout, stderr) = run_with(&["arn", "validate", "-q", "arn:aws:s3:::bucket", "arn:aws"], "");
+        assert_eq!(status, EXIT_INVALID);
+        assert_eq!(stdout, "");
+        assert!(stderr.starts_with("argument 2: Invalid ARN: \"arn:aws\""), "{stderr}");
+    }
+
+    #[test]
+    fn check_invalid_utf8() {
+        let cli = Cli::try_parse_from(["arn", "validate"]).unwrap();
+        let stdin: &[u8] = b"arn:aws:s3:::a\narn:aws:s3:::\xff\narn:aws:s3:::b";
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let status = run(cli, stdin, &mut stdout, &mut stderr).unwrap();
+        assert_eq!(status, EXIT_INVALID);
+        assert_eq!(String::from_utf8(stdout).unwrap(), "arn:aws:s3:::a\narn:aws:s3:::b\n");
+        assert_eq!(
+            String::from_utf8(stderr).unwrap(),
+            "line 2: Invalid UTF-8: invalid utf-8 sequence of 1 bytes from index 13\n"
+        );
+    }
+
+    #[test]
+    fn check_strict() {
+        let args = ["arn", "validate", "arn:aws:ec2:local:123456789012:instance/i-1"];
+        assert_eq!(run_with(&args, "").0, EXIT_OK);
+
+        let (status, _, stderr) = run_with(&["arn", "--strict", "validate", args[2]], "");
+        assert_eq!(status, EXIT_INVALID);
+        assert!(stderr.contains("rejected by the validation profile"), "{stderr}");
+    }
+
+    #[test]
+    fn check_explode() {
+        let (status, stdout, _) = run_with(&["arn", "explode"], "arn:aws:ec2:us-east-1:123456789012:instance/i-1\n");
+        assert_eq!(status, EXIT_OK);
+        let value: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "arn": "arn:aws:ec2:us-east-1:123456789012:instance/i-1",
+                "partition": "aws",
+                "service": "ec2",
+                "region": "us-east-1",
+                "account_id": "123456789012",
+                "resource": "instance/i-1",
+            })
+        );
+
+        let (status, stdout, _) = run_with(&["arn", "explode", "bogus"], "");
+        assert_eq!(status, EXIT_INVALID);
+        assert_eq!(stdout, "");
+    }
+
+    #[test]
+    fn check_sort() {
+        let stdin = "arn:aws:s3:::b\narn:aws:ec2:us-east-1:123456789012:instance/i-1\narn:aws:s3:::a\narn:aws:s3:::b\n";
+        let (status, stdout, _) = run_with(&["arn", "sort"], stdin);
+        assert_eq!(status, EXIT_OK);
+        assert_eq!(
+            stdout,
+            "arn:aws:ec2:us-east-1:123456789012:instance/i-1\narn:aws:s3:::a\narn:aws:s3:::b\narn:aws:s3:::b\n"
+        );
+
+        let (status, stdout, stderr) = run_with(&["arn", "sort", "-u"], &format!("{stdin}arn:aws:s3\n"));
+        assert_eq!(status, EXIT_INVALID);
+        assert_eq!(stdout, "arn:aws:ec2:us-east-1:123456789012:instance/i-1\narn:aws:s3:::a\narn:aws:s3:::b\n");
+        assert!(stderr.starts_with("line 5: "), "{stderr}");
+    }
+
+    #[test]
+    fn check_usage() {
+        assert!(Cli::try_parse_from(["arn"]).is_err());
+        assert!(Cli::try_parse_from(["arn", "frobnicate"]).is_err());
+    }
+}
+// end tests -- do not delete; needed for coverage.
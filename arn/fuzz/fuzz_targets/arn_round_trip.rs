@@ -0,0 +1,8 @@
+#![no_main]
+use {libfuzzer_sys::fuzz_target, scratchstack_arn::Arn, std::str::FromStr};
+
+fuzz_target!(|arn: Arn| {
+    let s = arn.to_string();
+    assert_eq!(Arn::from_str(&s).unwrap(), arn);
+    assert_eq!(Arn::new(arn.partition(), arn.service(), arn.region(), arn.account_id(), arn.resource()).unwrap(), arn);
+});
@@ -0,0 +1,125 @@
+use {
+    crate::Arn,
+    arbitrary::{Arbitrary, Error as ArbitraryError, Result as ArbitraryResult, Unstructured},
+};
+
+/// Lowercase letters, including non-ASCII letters and letters from scripts without case.
+const LETTERS: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+    'x', 'y', 'z', 'é', 'ç', 'ø', 'ò', 'ệ', 'ắ', 'œ', '中', '国', '日', '本', '東', '京',
+];
+
+const DIGITS: &[char] = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Characters that commonly appear in resources, in addition to lowercase letters and digits.
+const RESOURCE_CHARS: &[char] = &['/', ':', '-', '_', '.', '*', '?', '+', '=', '@', ' ', 'A', 'Q', 'Z', 'É'];
+
+/// Generate between 1 and `max_len` characters from the given pools.
+fn chars(u: &mut Unstructured<'_>, pools: &[&[char]], max_len: usize, s: &mut String) -> ArbitraryResult<()> {
+    for _ in 0..u.int_in_range(1..=max_len)? {
+        let pool = u.choose(pools)?;
+        s.push(*u.choose(pool)?);
+    }
+
+    Ok(())
+}
+
+/// Generate between 1 and `max_labels` labels from the given pools, separated by `-`.
+fn dashed(u: &mut Unstructured<'_>, pools: &[&[char]], max_labels: usize, max_len: usize) -> ArbitraryResult<String> {
+    let mut s = String::new();
+    for i in 0..u.int_in_range(1..=max_labels)? {
+        if i > 0 {
+            s.push('-');
+        }
+        chars(u, pools, max_len, &mut s)?;
+    }
+
+    Ok(s)
+}
+
+/// Generate a region in the form `name-digits`, optionally followed by a local region, or the special region `local`.
+fn region(u: &mut Unstructured<'_>) -> ArbitraryResult<String> {
+    if u.ratio(1, 16)? {
+        return Ok("local".to_string());
+    }
+
+    let mut s = dashed(u, &[LETTERS], 3, 8)?;
+    s.push('-');
+    chars(u, &[DIGITS], 2, &mut s)?;
+
+    if u.ratio(1, 4)? {
+        s.push('-');
+        s.push_str(&dashed(u, &[LETTERS], 2, 5)?);
+        s.push('-');
+        chars(u, &[DIGITS], 2, &mut s)?;
+    }
+
+    Ok(s)
+}
+
+/// Generates ARNs that are accepted by [Arn::new]. Regions and account ids may be empty; partitions, services, and
+/// regions may contain non-ASCII characters.
+impl<'a> Arbitrary<'a> for Arn {
+    fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+        // At most 2 labels of 4 characters of 3 bytes each keeps the partition within 32 bytes.
+        let partition = dashed(u, &[LETTERS, DIGITS], 2, 4)?;
+        let service = dashed(u, &[LETTERS, DIGITS], 2, 12)?;
+        let region = if u.ratio(1, 4)? {
+            String::new()
+        } else {
+            region(u)?
+        };
+        let account_id = match u.int_in_range(0..=3)? {
+            0 => String::new(),
+            1 => "aws".to_string(),
+            _ => {
+                let mut s = String::with_capacity(12);
+                for _ in 0..12 {
+                    s.push(*u.choose(DIGITS)?);
+                }
+                s
+            }
+        };
+
+        let mut resource = String::new();
+        chars(u, &[LETTERS, DIGITS, RESOURCE_CHARS], 64, &mut resource)?;
+
+        Arn::new(&partition, &service, &region, &account_id, &resource).map_err(|_| ArbitraryError::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::Arn,
+        arbitrary::{Arbitrary, Unstructured},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_arbitrary() {
+        let mut seen_unicode = false;
+        let mut seen_local_region = false;
+
+        for seed in 0u32..512 {
+            // A cheap deterministic byte stream.
+            let data: Vec<u8> =
+                (0..1024u32).map(|i| (i.wrapping_mul(2654435761).wrapping_add(seed * 40503) >> 13) as u8).collect();
+            let mut u = Unstructured::new(&data);
+            let arn = Arn::arbitrary(&mut u).unwrap();
+            assert_eq!(Arn::from_str(&arn.to_string()).unwrap(), arn);
+
+            seen_unicode |= !arn.partition().is_ascii() || !arn.region().is_ascii();
+            seen_local_region |=
+                arn.region().trim_end_matches(|c: char| c.is_ascii_digit()).contains(|c: char| c.is_ascii_digit());
+        }
+
+        assert!(seen_unicode);
+        assert!(seen_local_region);
+
+        // An empty input still produces a valid ARN.
+        Arn::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -1,18 +1,26 @@
 use {
     crate::{
-        utils::{validate_account_id, validate_partition, validate_region, validate_service},
-        ArnError,
+        url, utils::normalize, ArnError, ArnParseError, ArnRef, PartitionTranslation, ScratchstackRelaxed,
+        TranslationError, ValidationProfile,
     },
-    serde::{de, Deserialize, Serialize},
-    std::{
+    alloc::{
+        borrow::Cow,
+        format,
+        string::{String, ToString},
+    },
+    core::{
         cmp::Ordering,
         fmt::{Display, Formatter, Result as FmtResult},
         hash::Hash,
         str::FromStr,
     },
+    serde::{de, Deserialize, Serialize},
 };
 
-const PARTITION_START: usize = 4;
+#[cfg(feature = "std")]
+use crate::PartitionRegistry;
+
+pub(crate) const PARTITION_START: usize = 4;
 
 /// An Amazon Resource Name (ARN) representing an exact resource.
 ///
@@ -33,19 +41,23 @@ impl Arn {
     /// Create a new ARN from the specified components.
     ///
     /// * `partition` - The partition the resource is in (required). This is usually `aws`, `aws-cn`, or `aws-us-gov`
-    ///     for actual AWS resources, but may be any string meeting the rules specified in [validate_partition] for
-    ///     non-AWS resources.
+    ///   for actual AWS resources, but may be any string meeting the rules specified in [validate_partition] for
+    ///   non-AWS resources.
     /// * `service` - The service the resource belongs to (required). This is a service name like `ec2` or `s3`.
-    ///     Non-AWS resources must conform to the naming rules specified in [validate_service].
+    ///   Non-AWS resources must conform to the naming rules specified in [validate_service].
     /// * `region` - The region the resource is in (optional). If the resource is regional (and may other regions
-    ///     may have the resources with the same name), this is the region name. If the resource is global, this is
-    ///     empty. This is usually a region name like `us-east-1` or `us-west-2`, but may be any string meeting the
-    ///     rules specified in [validate_region].
+    ///   may have the resources with the same name), this is the region name. If the resource is global, this is
+    ///   empty. This is usually a region name like `us-east-1` or `us-west-2`, but may be any string meeting the
+    ///   rules specified in [validate_region].
     /// * `account_id` - The account ID the resource belongs to (optional). This is the 12-digit account ID or the
-    ///     string `aws` for certain AWS-owned resources. Some resources (such as S3 buckets and objects) do not need
-    ///     the account ID (the bucket name is globally unique within a partition), so this may be empty.
+    ///   string `aws` for certain AWS-owned resources. Some resources (such as S3 buckets and objects) do not need
+    ///   the account ID (the bucket name is globally unique within a partition), so this may be empty.
     /// * `resource` - The resource name (required). This is the name of the resource. The formatting is
-    ///     service-specific, but must be a valid UTF-8 string.
+    ///   service-specific, but must be a valid UTF-8 string.
+    ///
+    /// [validate_partition]: crate::utils::validate_partition
+    /// [validate_region]: crate::utils::validate_region
+    /// [validate_service]: crate::utils::validate_service
     ///
     /// # Errors
     ///
@@ -53,6 +65,12 @@ impl Arn {
     /// * If the service is invalid, [ArnError::InvalidService] is returned.
     /// * If the region is invalid, [ArnError::InvalidRegion] is returned.
     /// * If the account ID is invalid, [ArnError::InvalidAccountId] is returned.
+    /// * If the resource is invalid (see [validate_resource](crate::utils::validate_resource)) or the ARN would be
+    ///   longer than [MAX_ARN_LENGTH](crate::utils::MAX_ARN_LENGTH) characters, [ArnError::InvalidResource] is
+    ///   returned.
+    ///
+    /// To accept resources from legacy data that do not meet these requirements, use [Arn::new_with_profile] with
+    /// the [LegacyResources](crate::LegacyResources) profile.
     pub fn new(
         partition: &str,
         service: &str,
@@ -60,30 +78,169 @@ impl Arn {
         account_id: &str,
         resource: &str,
     ) -> Result<Self, ArnError> {
-        validate_partition(partition)?;
-        validate_service(service)?;
+        Self::new_with_profile(partition, service, region, account_id, resource, &ScratchstackRelaxed)
+    }
+
+    /// Create a new ARN from the specified components, validating them according to the specified
+    /// [ValidationProfile].
+    ///
+    /// The arguments are the same as for [Arn::new]. Empty regions and account IDs are always accepted; non-empty
+    /// components are checked with the corresponding method of `profile`.
+    ///
+    /// # Errors
+    ///
+    /// * If the partition is invalid, [ArnError::InvalidPartition] is returned.
+    /// * If the service is invalid, [ArnError::InvalidService] is returned.
+    /// * If the region is invalid, [ArnError::InvalidRegion] is returned.
+    /// * If the account ID is invalid, [ArnError::InvalidAccountId] is returned.
+    /// * If the resource is invalid or the ARN is too long, [ArnError::InvalidResource] is returned.
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_service(service)?;
         if !region.is_empty() {
-            validate_region(region)?
+            profile.validate_region(region)?
         }
         if !account_id.is_empty() {
-            validate_account_id(account_id)?
+            profile.validate_account_id(account_id)?
         }
+        profile.validate_resource(resource)?;
+        validate_arn_length(
+            profile,
+            &["arn:", partition, ":", service, ":", region, ":", account_id, ":", resource],
+            resource,
+        )?;
 
         // Safety: We have met the preconditions specified for new_unchecked above.
         unsafe { Ok(Self::new_unchecked(partition, service, region, account_id, resource)) }
     }
 
+    /// Create a new ARN from the specified components, converting the partition, service, and region to NFKC
+    /// normalized form before validating them.
+    ///
+    /// This accepts components in decomposed form (e.g. `c` followed by a combining cedilla) that [Arn::new] rejects.
+    /// Since the normalized form is stored, ARNs that render identically compare equal and have the same hash.
+    ///
+    /// # Errors
+    ///
+    /// The errors are the same as for [Arn::new], and contain the normalized component.
+    pub fn new_normalized(
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource: &str,
+    ) -> Result<Self, ArnError> {
+        Self::new_normalized_with_profile(partition, service, region, account_id, resource, &ScratchstackRelaxed)
+    }
+
+    /// Create a new ARN from the specified components, converting the partition, service, and region to NFKC
+    /// normalized form before validating them according to the specified [ValidationProfile].
+    pub fn new_normalized_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        Self::new_with_profile(
+            &normalize(partition),
+            &normalize(service),
+            &normalize(region),
+            account_id,
+            resource,
+            profile,
+        )
+    }
+
+    /// Parse an ARN from a string, validating the components according to the specified [ValidationProfile].
+    ///
+    /// # Errors
+    ///
+    /// The errors are the same as for [Arn::from_str](FromStr::from_str).
+    pub fn from_str_with_profile<P: ValidationProfile + ?Sized>(s: &str, profile: &P) -> Result<Self, ArnError> {
+        ArnRef::parse_with_profile(s, profile).map(|arn_ref| arn_ref.to_owned())
+    }
+
+    /// Parse an ARN from a string, converting the partition, service, and region to NFKC normalized form before
+    /// validating them.
+    ///
+    /// # Errors
+    ///
+    /// The errors are the same as for [Arn::from_str](FromStr::from_str).
+    pub fn from_str_normalized(s: &str) -> Result<Self, ArnError> {
+        Self::from_str_normalized_with_profile(s, &ScratchstackRelaxed)
+    }
+
+    /// Parse an ARN from a string, converting the partition, service, and region to NFKC normalized form before
+    /// validating them according to the specified [ValidationProfile].
+    pub fn from_str_normalized_with_profile<P: ValidationProfile + ?Sized>(
+        s: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        // Split on ASCII `:` before normalizing, since NFKC maps some characters (e.g. U+FF1A, U+FE55) to `:`.
+        let mut parts = s.splitn(6, ':');
+        let (Some(scheme), Some(partition), Some(service), Some(region), Some(account_id), Some(resource)) =
+            (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ArnError::InvalidArn(s.to_string()));
+        };
+
+        if scheme != "arn" {
+            return Err(ArnError::InvalidScheme(scheme.to_string()));
+        }
+
+        // If nothing needs normalizing, parse the input directly.
+        if [partition, service, region].iter().all(|c| matches!(normalize(c), Cow::Borrowed(_))) {
+            return Self::from_str_with_profile(s, profile);
+        }
+
+        Self::new_normalized_with_profile(partition, service, region, account_id, resource, profile)
+    }
+
+    /// Parse an ARN from a string, returning a detailed [ArnParseError] on failure.
+    ///
+    /// This accepts the same ARNs as [Arn::from_str](FromStr::from_str), but the error records which component failed,
+    /// where in the input the offending characters are, and why. Use the alternate format (`{:#}`) to render the
+    /// input with a caret marking the error.
+    pub fn from_str_detailed(s: &str) -> Result<Self, ArnParseError> {
+        ArnRef::parse_detailed(s).map(|arn_ref| arn_ref.to_owned())
+    }
+
+    /// Parse an ARN from a string, validating the components according to the specified [ValidationProfile] and
+    /// returning a detailed [ArnParseError] on failure.
+    pub fn from_str_detailed_with_profile<P: ValidationProfile + ?Sized>(
+        s: &str,
+        profile: &P,
+    ) -> Result<Self, ArnParseError> {
+        ArnRef::parse_detailed_with_profile(s, profile).map(|arn_ref| arn_ref.to_owned())
+    }
+
     /// Create a new ARN from the specified components, bypassing any validation.
     ///
     /// # Safety
     ///
-    /// The following constraints must be met:
+    /// The following constraints must be met, either under the rules of the default [ScratchstackRelaxed] profile
+    /// or another [ValidationProfile]:
     ///
     /// * `partition` - Must meet the rules specified in [validate_partition].
     /// * `service` - Must meet the rules specified in [validate_service].
     /// * `region` - Must be empty or meet the rules specified in [validate_region].
     /// * `account_id` - Must be empty, a 12 ASCII digit account ID, or the string `aws`.
     /// * `resource` - A valid UTF-8 string.
+    ///
+    /// In all cases, the partition, service, region, and account ID must not contain a `:` character.
+    ///
+    /// [validate_partition]: crate::utils::validate_partition
+    /// [validate_region]: crate::utils::validate_region
+    /// [validate_service]: crate::utils::validate_service
     pub unsafe fn new_unchecked(
         partition: &str,
         service: &str,
@@ -106,6 +263,43 @@ impl Arn {
         }
     }
 
+    /// Create a new ARN from an already-formatted ARN string and its component offsets, bypassing any validation.
+    ///
+    /// # Safety
+    ///
+    /// `arn` must be a valid ARN string, and the offsets must point to the first byte of the corresponding component
+    /// within `arn`.
+    pub(crate) unsafe fn from_parts_unchecked(
+        arn: String,
+        service_start: usize,
+        region_start: usize,
+        account_id_start: usize,
+        resource_start: usize,
+    ) -> Self {
+        Self {
+            arn,
+            service_start,
+            region_start,
+            account_id_start,
+            resource_start,
+        }
+    }
+
+    /// Retrieve a borrowed [ArnRef] view of this ARN.
+    #[inline]
+    pub fn as_arn_ref(&self) -> ArnRef<'_> {
+        // Safety: This ARN was validated when it was created, so the offsets are valid for the ARN string.
+        unsafe {
+            ArnRef::from_parts_unchecked(
+                &self.arn,
+                self.service_start,
+                self.region_start,
+                self.account_id_start,
+                self.resource_start,
+            )
+        }
+    }
+
     /// Retrieve the partition the resource is in.
     #[inline]
     pub fn partition(&self) -> &str {
@@ -135,6 +329,372 @@ impl Arn {
     pub fn resource(&self) -> &str {
         &self.arn[self.resource_start..]
     }
+
+    /// Retrieve the resource type, such as `instance` for `instance/i-1234567890abcdef0` or `function` for
+    /// `function:my-function`.
+    ///
+    /// See [Arn::resource_qualifier] for the resource conventions understood.
+    ///
+    /// # Errors
+    ///
+    /// If the resource does not follow either convention, [ArnError::InvalidResource] is returned.
+    #[inline]
+    pub fn resource_type(&self) -> Result<&str, ArnError> {
+        split_resource(self.resource()).map(|(resource_type, _, _)| resource_type)
+    }
+
+    /// Retrieve the resource id, such as `i-1234567890abcdef0` for `instance/i-1234567890abcdef0` or `/aws/x` for
+    /// `log-group:/aws/x:*`.
+    ///
+    /// See [Arn::resource_qualifier] for the resource conventions understood.
+    ///
+    /// # Errors
+    ///
+    /// If the resource does not follow either convention, [ArnError::InvalidResource] is returned.
+    #[inline]
+    pub fn resource_id(&self) -> Result<&str, ArnError> {
+        split_resource(self.resource()).map(|(_, resource_id, _)| resource_id)
+    }
+
+    /// Retrieve the resource qualifier, if any, such as `alias` for `function:name:alias`.
+    ///
+    /// Two resource conventions are understood:
+    ///
+    /// * Slash-separated: `resource-type/resource-id`. The resource id is everything after the first `/` and may itself
+    ///   contain `/` or `:` characters (e.g. `user/path/user-name`). These resources never have a qualifier.
+    /// * Colon-separated: `resource-type:resource-id` or `resource-type:resource-id:qualifier`. If a second `:`
+    ///   appears, the qualifier is everything after the last `:` (e.g. `log-group:/aws/x:*` has a resource id of
+    ///   `/aws/x` and a qualifier of `*`).
+    ///
+    /// The convention is chosen by whichever of `/` or `:` appears first in the resource.
+    ///
+    /// # Errors
+    ///
+    /// If the resource contains neither separator, or if the resource type, resource id, or qualifier is empty,
+    /// [ArnError::InvalidResource] is returned.
+    #[inline]
+    pub fn resource_qualifier(&self) -> Result<Option<&str>, ArnError> {
+        split_resource(self.resource()).map(|(_, _, qualifier)| qualifier)
+    }
+
+    /// Verify that this ARN's partition and region are known to `registry`.
+    ///
+    /// This is an opt-in check in addition to the syntactic validation performed when the ARN was created. The
+    /// region, if not empty, must be a known region of the partition or match the partition's region regex.
+    ///
+    /// # Errors
+    ///
+    /// * If the partition is not in the registry, [ArnError::InvalidPartition] is returned.
+    /// * If the region does not belong to the partition, [ArnError::InvalidRegion] is returned.
+    #[cfg(feature = "std")]
+    pub fn validate_in(&self, registry: &PartitionRegistry) -> Result<(), ArnError> {
+        let Some(partition) = registry.get(self.partition()) else {
+            return Err(ArnError::InvalidPartition(self.partition().to_string()));
+        };
+
+        let region = self.region();
+        if !region.is_empty() && !partition.matches_region(region) {
+            return Err(ArnError::InvalidRegion(region.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Percent-encode this ARN for use as a single URL path segment.
+    ///
+    /// This follows the label encoding used by AWS REST-JSON services (e.g. the resource tagging APIs): every byte
+    /// other than ASCII letters, digits, `-`, `.`, `_`, and `~` is encoded as `%XX` with uppercase hex digits. In
+    /// particular, `:` is encoded as `%3A` and `/` as `%2F`, so `arn:aws:s3:::bucket/key` becomes
+    /// `arn%3Aaws%3As3%3A%3A%3Abucket%2Fkey`.
+    pub fn to_url_segment(&self) -> String {
+        url::encode_segment(&self.arn)
+    }
+
+    /// Decode an ARN from a percent-encoded URL path segment, such as one produced by [Arn::to_url_segment].
+    ///
+    /// Hex digits may be uppercase or lowercase, and characters that did not need to be encoded are accepted as-is.
+    /// The decoded ARN is validated as by [Arn::from_str](FromStr::from_str).
+    ///
+    /// # Errors
+    ///
+    /// If the segment contains a malformed `%` escape or does not decode to UTF-8, [ArnError::InvalidArn] is returned
+    /// with the segment. Otherwise, the errors are the same as for [Arn::from_str](FromStr::from_str).
+    pub fn from_url_segment(segment: &str) -> Result<Self, ArnError> {
+        Self::from_str(&url::decode_segment(segment)?)
+    }
+
+    /// Create a copy of this ARN with the specified components replaced, checking only the length of the result.
+    ///
+    /// The caller must have validated the replaced components. The length is checked against `profile` only if the
+    /// result is longer than this ARN, so ARNs created under a profile with a higher (or no) length limit can still
+    /// have components replaced.
+    pub(crate) fn with_components<P: ValidationProfile + ?Sized>(
+        &self,
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        let pieces = ["arn:", partition, ":", service, ":", region, ":", account_id, ":", resource];
+        if pieces.iter().map(|p| p.chars().count()).sum::<usize>() > self.arn.chars().count() {
+            validate_arn_length(profile, &pieces, resource)?;
+        }
+
+        // Safety: The unchanged components were validated when this ARN was created, the caller has validated the
+        // changed components, and the length is within the limits of either the original profile or `profile`.
+        unsafe { Ok(Self::new_unchecked(partition, service, region, account_id, resource)) }
+    }
+
+    /// Create a copy of this ARN in a different partition.
+    ///
+    /// Only the new partition is validated, using the rules in
+    /// [utils::validate_partition](crate::utils::validate_partition).
+    ///
+    /// # Errors
+    ///
+    /// If the partition is invalid, [ArnError::InvalidPartition] is returned. If the resulting ARN is longer than this
+    /// ARN and too long, [ArnError::InvalidResource] is returned.
+    pub fn with_partition(&self, partition: &str) -> Result<Self, ArnError> {
+        self.with_partition_with_profile(partition, &ScratchstackRelaxed)
+    }
+
+    /// Create a copy of this ARN in a different partition, validating the new partition according to the specified
+    /// [ValidationProfile].
+    pub fn with_partition_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        partition: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        self.with_components(partition, self.service(), self.region(), self.account_id(), self.resource(), profile)
+    }
+
+    /// Create a copy of this ARN for a different service.
+    ///
+    /// Only the new service is validated, using the rules in [utils::validate_service](crate::utils::validate_service).
+    ///
+    /// # Errors
+    ///
+    /// If the service is invalid, [ArnError::InvalidService] is returned. If the resulting ARN is longer than this ARN
+    /// and too long, [ArnError::InvalidResource] is returned.
+    pub fn with_service(&self, service: &str) -> Result<Self, ArnError> {
+        self.with_service_with_profile(service, &ScratchstackRelaxed)
+    }
+
+    /// Create a copy of this ARN for a different service, validating the new service according to the specified
+    /// [ValidationProfile].
+    pub fn with_service_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        service: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_service(service)?;
+        self.with_components(self.partition(), service, self.region(), self.account_id(), self.resource(), profile)
+    }
+
+    /// Create a copy of this ARN in a different region. The region may be empty.
+    ///
+    /// Only the new region is validated, using the rules in [utils::validate_region](crate::utils::validate_region).
+    ///
+    /// # Errors
+    ///
+    /// If the region is invalid, [ArnError::InvalidRegion] is returned. If the resulting ARN is longer than this ARN
+    /// and too long, [ArnError::InvalidResource] is returned.
+    pub fn with_region(&self, region: &str) -> Result<Self, ArnError> {
+        self.with_region_with_profile(region, &ScratchstackRelaxed)
+    }
+
+    /// Create a copy of this ARN in a different region, validating the new region according to the specified
+    /// [ValidationProfile]. The region may be empty.
+    pub fn with_region_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        region: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        if !region.is_empty() {
+            profile.validate_region(region)?;
+        }
+        self.with_components(self.partition(), self.service(), region, self.account_id(), self.resource(), profile)
+    }
+
+    /// Create a copy of this ARN in a different account. The account id may be empty.
+    ///
+    /// Only the new account id is validated, using the rules in
+    /// [utils::validate_account_id](crate::utils::validate_account_id).
+    ///
+    /// # Errors
+    ///
+    /// If the account id is invalid, [ArnError::InvalidAccountId] is returned. If the resulting ARN is longer than
+    /// this ARN and too long, [ArnError::InvalidResource] is returned.
+    pub fn with_account_id(&self, account_id: &str) -> Result<Self, ArnError> {
+        self.with_account_id_with_profile(account_id, &ScratchstackRelaxed)
+    }
+
+    /// Create a copy of this ARN in a different account, validating the new account id according to the specified
+    /// [ValidationProfile]. The account id may be empty.
+    pub fn with_account_id_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        account_id: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        if !account_id.is_empty() {
+            profile.validate_account_id(account_id)?;
+        }
+        self.with_components(self.partition(), self.service(), self.region(), account_id, self.resource(), profile)
+    }
+
+    /// Create a copy of this ARN with a different resource.
+    ///
+    /// Only the new resource is validated, using the rules in
+    /// [utils::validate_resource](crate::utils::validate_resource).
+    ///
+    /// # Errors
+    ///
+    /// If the resource is invalid or the resulting ARN is longer than this ARN and too long,
+    /// [ArnError::InvalidResource] is returned.
+    pub fn with_resource(&self, resource: &str) -> Result<Self, ArnError> {
+        self.with_resource_with_profile(resource, &ScratchstackRelaxed)
+    }
+
+    /// Create a copy of this ARN with a different resource, validating the new resource according to the specified
+    /// [ValidationProfile].
+    pub fn with_resource_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        resource: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_resource(resource)?;
+        self.with_components(self.partition(), self.service(), self.region(), self.account_id(), resource, profile)
+    }
+
+    /// Translate this ARN to another partition, such as from `aws` to `aws-cn`.
+    ///
+    /// The partition is replaced by the translation's target partition and the region, if not empty, by its
+    /// counterpart in the target partition. The service, account id, and resource are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * If this ARN is not in the translation's source partition, [TranslationError::PartitionMismatch] is returned.
+    /// * If the region has no counterpart in the target partition, [TranslationError::UnmappedRegion] is returned.
+    /// * If the resulting ARN is too long, [TranslationError::Arn] wrapping [ArnError::InvalidResource] is returned.
+    pub fn translate(&self, translation: &PartitionTranslation) -> Result<Self, TranslationError> {
+        if self.partition() != translation.from_partition() {
+            return Err(TranslationError::PartitionMismatch {
+                expected: translation.from_partition().to_string(),
+                actual: self.partition().to_string(),
+            });
+        }
+
+        // The partition and regions in the translation were validated when it was created.
+        let region = if self.region().is_empty() {
+            ""
+        } else {
+            translation.map_region(self.region())?
+        };
+
+        Ok(self.with_components(
+            translation.to_partition(),
+            self.service(),
+            region,
+            self.account_id(),
+            self.resource(),
+            &ScratchstackRelaxed,
+        )?)
+    }
+
+    /// Return the ARN of the parent of this resource in a `/`-separated hierarchy, or `None` if the resource has no
+    /// parent.
+    ///
+    /// The parent is the resource with its last `/`-separated segment removed; a trailing `/` is ignored. For example,
+    /// the parent of `bucket/a/b` and of `bucket/a/b/` is `bucket/a`, and `bucket` has no parent.
+    pub fn parent(&self) -> Option<Self> {
+        let resource = self.resource();
+        let trimmed = resource.strip_suffix('/').unwrap_or(resource);
+        let (parent, _) = trimmed.rsplit_once('/')?;
+        if parent.is_empty() {
+            return None;
+        }
+
+        // Safety: The partition, service, region, and account id are unchanged. The parent is a prefix of the resource
+        // of this ARN, so it is as valid as the resource and the ARN is shorter than this one.
+        unsafe { Some(Self::new_unchecked(self.partition(), self.service(), self.region(), self.account_id(), parent)) }
+    }
+
+    /// Return the ARN of a child of this resource in a `/`-separated hierarchy.
+    ///
+    /// `child` is appended to the resource, separated by a `/` unless the resource already ends with one. For example,
+    /// joining `key.txt` to `arn:aws:s3:::bucket` gives `arn:aws:s3:::bucket/key.txt`.
+    ///
+    /// # Errors
+    ///
+    /// If `child` is empty or invalid, or the resulting ARN is too long, [ArnError::InvalidResource] is returned.
+    pub fn join(&self, child: &str) -> Result<Self, ArnError> {
+        self.join_with_profile(child, &ScratchstackRelaxed)
+    }
+
+    /// Return the ARN of a child of this resource in a `/`-separated hierarchy, validating `child` and the length of
+    /// the result according to the specified [ValidationProfile].
+    pub fn join_with_profile<P: ValidationProfile + ?Sized>(&self, child: &str, profile: &P) -> Result<Self, ArnError> {
+        profile.validate_resource(child)?;
+
+        let resource = self.resource();
+        let resource = if resource.ends_with('/') {
+            format!("{resource}{child}")
+        } else {
+            format!("{resource}/{child}")
+        };
+        self.with_components(self.partition(), self.service(), self.region(), self.account_id(), &resource, profile)
+    }
+}
+
+/// Verify that the ARN composed of `pieces` does not exceed the maximum length allowed by `profile`.
+///
+/// If it does, [ArnError::InvalidResource] is returned for `resource`.
+pub(crate) fn validate_arn_length<P: ValidationProfile + ?Sized>(
+    profile: &P,
+    pieces: &[&str],
+    resource: &str,
+) -> Result<(), ArnError> {
+    let Some(max_length) = profile.max_arn_length() else {
+        return Ok(());
+    };
+
+    // The byte length is an upper bound on the character length; only count characters if necessary.
+    if pieces.iter().map(|p| p.len()).sum::<usize>() > max_length
+        && pieces.iter().map(|p| p.chars().count()).sum::<usize>() > max_length
+    {
+        return Err(ArnError::InvalidResource(resource.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Split a resource into its resource type, resource id, and optional qualifier. See [Arn::resource_qualifier] for
+/// the conventions understood.
+pub(crate) fn split_resource(resource: &str) -> Result<(&str, &str, Option<&str>), ArnError> {
+    let Some(sep_pos) = resource.find(['/', ':']) else {
+        return Err(ArnError::InvalidResource(resource.to_string()));
+    };
+
+    let resource_type = &resource[..sep_pos];
+    let rest = &resource[sep_pos + 1..];
+
+    let (resource_id, qualifier) = if resource.as_bytes()[sep_pos] == b'/' {
+        (rest, None)
+    } else {
+        match rest.rsplit_once(':') {
+            Some((resource_id, qualifier)) => (resource_id, Some(qualifier)),
+            None => (rest, None),
+        }
+    };
+
+    if resource_type.is_empty() || resource_id.is_empty() || qualifier == Some("") {
+        return Err(ArnError::InvalidResource(resource.to_string()));
+    }
+
+    Ok((resource_type, resource_id, qualifier))
 }
 
 impl Display for Arn {
@@ -154,22 +714,14 @@ impl FromStr for Arn {
     /// # Errors
     ///
     /// * If the ARN is not composed of 6 colon-separated components, [ArnError::InvalidArn] is returned.
-    /// * If the ARN does not start with `arn:`, [ArnError::InvalidArn] is returned.
+    /// * If the ARN does not start with `arn:`, [ArnError::InvalidScheme] is returned.
     /// * If the partition is invalid, [ArnError::InvalidPartition] is returned.
     /// * If the service is invalid, [ArnError::InvalidService] is returned.
     /// * If the region is invalid, [ArnError::InvalidRegion] is returned.
     /// * If the account ID is invalid, [ArnError::InvalidAccountId] is returned.
+    /// * If the resource is invalid or the ARN is too long, [ArnError::InvalidResource] is returned.
     fn from_str(s: &str) -> Result<Self, ArnError> {
-        let parts: Vec<&str> = s.splitn(6, ':').collect();
-        if parts.len() != 6 {
-            return Err(ArnError::InvalidArn(s.to_string()));
-        }
-
-        if parts[0] != "arn" {
-            return Err(ArnError::InvalidScheme(parts[0].to_string()));
-        }
-
-        Self::new(parts[1], parts[2], parts[3], parts[4], parts[5])
+        ArnRef::parse(s).map(|arn_ref| arn_ref.to_owned())
     }
 }
 
@@ -225,8 +777,8 @@ mod test {
     use {
         super::Arn,
         crate::{
-            utils::{validate_account_id, validate_region},
-            ArnError,
+            utils::{validate_account_id, validate_account_id_digits, validate_region},
+            ArnError, LegacyResources, ScratchstackRelaxed, ValidationProfile,
         },
         pretty_assertions::assert_eq,
         std::{
@@ -299,6 +851,196 @@ mod test {
         assert_eq!(arn.resource(), "instance/i-1234567890abcdef0");
     }
 
+    #[test]
+    fn check_invalid_resources() {
+        let err = Arn::new("aws", "s3", "", "", "").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("".to_string()));
+
+        let err = Arn::from_str("arn:aws:s3:::").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: """#);
+
+        let err = Arn::from_str("arn:aws:s3:::bucket/key\nwith-newline").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("bucket/key\nwith-newline".to_string()));
+
+        let err = Arn::new("aws", "s3", "", "", "bucket\x00").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("bucket\x00".to_string()));
+
+        // The ARN may be at most 2048 characters; "arn:aws:s3:::" is 13 characters.
+        let resource = "x".repeat(2048 - 13);
+        Arn::new("aws", "s3", "", "", &resource).unwrap();
+        Arn::from_str(&format!("arn:aws:s3:::{resource}")).unwrap();
+
+        let resource = "x".repeat(2048 - 12);
+        let err = Arn::new("aws", "s3", "", "", &resource).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource(resource.clone()));
+        let err = Arn::from_str(&format!("arn:aws:s3:::{resource}")).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource(resource));
+
+        // The limit is in characters, not bytes.
+        let resource = "é".repeat(2048 - 13);
+        Arn::new("aws", "s3", "", "", &resource).unwrap();
+
+        // Legacy data can bypass these checks.
+        let resource = format!("{}\n", "x".repeat(4096));
+        let arn = Arn::new_with_profile("aws", "s3", "", "", &resource, &LegacyResources(ScratchstackRelaxed)).unwrap();
+        assert_eq!(arn.resource(), resource);
+        let arn =
+            Arn::from_str_with_profile("arn:aws:s3:::", &LegacyResources::<ScratchstackRelaxed>::default()).unwrap();
+        assert_eq!(arn.resource(), "");
+    }
+
+    #[test]
+    fn check_with_components() {
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+
+        assert_eq!(
+            arn.with_partition("aws-cn").unwrap().to_string(),
+            "arn:aws-cn:ec2:us-east-1:123456789012:instance/i-1"
+        );
+        assert_eq!(arn.with_service("ssm").unwrap().service(), "ssm");
+        assert_eq!(
+            arn.with_region("us-west-2").unwrap().to_string(),
+            "arn:aws:ec2:us-west-2:123456789012:instance/i-1"
+        );
+        assert_eq!(arn.with_region("").unwrap().to_string(), "arn:aws:ec2::123456789012:instance/i-1");
+        assert_eq!(arn.with_account_id("210987654321").unwrap().account_id(), "210987654321");
+        assert_eq!(arn.with_account_id("").unwrap().to_string(), "arn:aws:ec2:us-east-1::instance/i-1");
+        assert_eq!(arn.with_resource("volume/vol-1").unwrap().resource(), "volume/vol-1");
+
+        // The result is identical to an ARN created directly.
+        let derived = arn.with_region("eu-west-1").unwrap();
+        assert_eq!(derived, Arn::from_str("arn:aws:ec2:eu-west-1:123456789012:instance/i-1").unwrap());
+        assert_eq!(derived.resource_start, Arn::from_str(&derived.to_string()).unwrap().resource_start);
+
+        assert_eq!(arn.with_partition("Aws").unwrap_err(), ArnError::InvalidPartition("Aws".into()));
+        assert_eq!(arn.with_service("").unwrap_err(), ArnError::InvalidService("".into()));
+        assert_eq!(arn.with_region("us-east").unwrap_err(), ArnError::InvalidRegion("us-east".into()));
+        assert_eq!(arn.with_account_id("1234").unwrap_err(), ArnError::InvalidAccountId("1234".into()));
+        assert_eq!(arn.with_resource("").unwrap_err(), ArnError::InvalidResource("".into()));
+        let long = "x".repeat(2048);
+        assert_eq!(arn.with_resource(&long).unwrap_err(), ArnError::InvalidResource(long));
+    }
+
+    #[test]
+    fn check_with_components_profile() {
+        struct OnPrem;
+
+        impl ValidationProfile for OnPrem {
+            fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+                validate_account_id_digits(account_id, 10)
+            }
+        }
+
+        let arn = Arn::from_str_with_profile("arn:aws:s3::1234567890:bucket", &OnPrem).unwrap();
+        assert_eq!(arn.with_account_id_with_profile("0987654321", &OnPrem).unwrap().account_id(), "0987654321");
+        assert_eq!(arn.with_account_id("0987654321").unwrap_err(), ArnError::InvalidAccountId("0987654321".into()));
+        assert_eq!(
+            arn.with_account_id_with_profile("123456789012", &OnPrem).unwrap_err(),
+            ArnError::InvalidAccountId("123456789012".into())
+        );
+
+        // Over-length legacy ARNs can have components replaced as long as they do not grow.
+        let legacy = LegacyResources(ScratchstackRelaxed);
+        let resource = "y".repeat(2100);
+        let arn = Arn::new_with_profile("aws", "ec2", "us-west-2", "123456789012", &resource, &legacy).unwrap();
+        let moved = arn.with_region("us-east-1").unwrap();
+        assert_eq!(moved.region(), "us-east-1");
+        assert_eq!(moved.resource(), resource);
+        assert_eq!(arn.with_account_id("").unwrap().account_id(), "");
+        assert_eq!(arn.with_region("ap-southeast-1").unwrap_err(), ArnError::InvalidResource(resource.clone()));
+        assert_eq!(arn.with_region_with_profile("ap-southeast-1", &legacy).unwrap().region(), "ap-southeast-1");
+        assert_eq!(arn.with_partition_with_profile("aws-cn", &legacy).unwrap().partition(), "aws-cn");
+        assert_eq!(arn.with_service_with_profile("ssm", &legacy).unwrap().service(), "ssm");
+        assert_eq!(arn.with_resource_with_profile("", &legacy).unwrap().resource(), "");
+        assert_eq!(arn.join("z").unwrap_err(), ArnError::InvalidResource(format!("{resource}/z")));
+        assert_eq!(arn.join_with_profile("z", &legacy).unwrap().resource(), format!("{resource}/z"));
+        assert_eq!(arn.parent(), None);
+    }
+
+    #[test]
+    fn check_url_segment() {
+        let arn = Arn::from_str("arn:aws:s3:::bucket/photos/cat picture.jpg").unwrap();
+        let segment = arn.to_url_segment();
+        assert_eq!(segment, "arn%3Aaws%3As3%3A%3A%3Abucket%2Fphotos%2Fcat%20picture.jpg");
+        assert_eq!(Arn::from_url_segment(&segment).unwrap(), arn);
+
+        let arn = Arn::from_str("arn:aws-中国:ec2:日本-東京-1:123456789012:instance/i-1").unwrap();
+        assert_eq!(Arn::from_url_segment(&arn.to_url_segment()).unwrap(), arn);
+
+        // Lowercase hex digits and unencoded characters are accepted.
+        let arn = Arn::from_url_segment("arn%3aaws%3aiam::123456789012%3auser/alice").unwrap();
+        assert_eq!(arn.to_string(), "arn:aws:iam::123456789012:user/alice");
+
+        assert_eq!(Arn::from_url_segment("arn%3Aaws%3").unwrap_err(), ArnError::InvalidArn("arn%3Aaws%3".into()));
+        assert_eq!(Arn::from_url_segment("arn%3Aaws%3As3").unwrap_err(), ArnError::InvalidArn("arn:aws:s3".into()));
+        assert_eq!(
+            Arn::from_url_segment("arn%3AAws%3As3%3A%3A%3Ab").unwrap_err(),
+            ArnError::InvalidPartition("Aws".into())
+        );
+    }
+
+    #[test]
+    fn check_parent_join() {
+        let bucket = Arn::from_str("arn:aws:s3:::bucket").unwrap();
+        assert_eq!(bucket.parent(), None);
+
+        let object = bucket.join("a").unwrap().join("b").unwrap();
+        assert_eq!(object.to_string(), "arn:aws:s3:::bucket/a/b");
+        assert_eq!(object.parent().unwrap().to_string(), "arn:aws:s3:::bucket/a");
+        assert_eq!(object.parent().unwrap().parent().unwrap(), bucket);
+
+        let dir = Arn::from_str("arn:aws:s3:::bucket/a/b/").unwrap();
+        assert_eq!(dir.parent().unwrap().resource(), "bucket/a");
+        assert_eq!(dir.join("c.txt").unwrap().resource(), "bucket/a/b/c.txt");
+        assert_eq!(bucket.join("x/y.txt").unwrap().resource(), "bucket/x/y.txt");
+
+        assert_eq!(Arn::from_str("arn:aws:s3:::/a").unwrap().parent(), None);
+
+        // The parent of an over-length legacy ARN is built without re-checking its length.
+        let resource = format!("{}/b", "y".repeat(2100));
+        let arn = Arn::new_with_profile("aws", "s3", "", "", &resource, &LegacyResources(ScratchstackRelaxed)).unwrap();
+        assert_eq!(arn.parent().unwrap().resource(), "y".repeat(2100));
+        assert_eq!(bucket.join("").unwrap_err(), ArnError::InvalidResource("".into()));
+        assert_eq!(bucket.join("a\nb").unwrap_err(), ArnError::InvalidResource("a\nb".into()));
+    }
+
+    #[test]
+    fn check_resource_parts() {
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1234567890abcdef0").unwrap();
+        assert_eq!(arn.resource_type().unwrap(), "instance");
+        assert_eq!(arn.resource_id().unwrap(), "i-1234567890abcdef0");
+        assert_eq!(arn.resource_qualifier().unwrap(), None);
+
+        let arn = Arn::from_str("arn:aws:iam::123456789012:user/path/to/user-name").unwrap();
+        assert_eq!(arn.resource_type().unwrap(), "user");
+        assert_eq!(arn.resource_id().unwrap(), "path/to/user-name");
+        assert_eq!(arn.resource_qualifier().unwrap(), None);
+
+        let arn = Arn::from_str("arn:aws:lambda:us-east-1:123456789012:function:my-function").unwrap();
+        assert_eq!(arn.resource_type().unwrap(), "function");
+        assert_eq!(arn.resource_id().unwrap(), "my-function");
+        assert_eq!(arn.resource_qualifier().unwrap(), None);
+
+        let arn = Arn::from_str("arn:aws:lambda:us-east-1:123456789012:function:my-function:alias").unwrap();
+        assert_eq!(arn.resource_type().unwrap(), "function");
+        assert_eq!(arn.resource_id().unwrap(), "my-function");
+        assert_eq!(arn.resource_qualifier().unwrap(), Some("alias"));
+
+        let arn = Arn::from_str("arn:aws:logs:us-east-1:123456789012:log-group:/aws/x:*").unwrap();
+        assert_eq!(arn.resource_type().unwrap(), "log-group");
+        assert_eq!(arn.resource_id().unwrap(), "/aws/x");
+        assert_eq!(arn.resource_qualifier().unwrap(), Some("*"));
+
+        for resource in ["bucket", "/instance", ":function", "instance/", "function:", "function:name:", "function::1"]
+        {
+            let arn = Arn::new("aws", "svc", "", "", resource).unwrap();
+            let expected = ArnError::InvalidResource(resource.to_string());
+            assert_eq!(arn.resource_type().unwrap_err(), expected);
+            assert_eq!(arn.resource_id().unwrap_err(), expected);
+            assert_eq!(arn.resource_qualifier().unwrap_err(), expected);
+        }
+    }
+
     #[test]
     fn check_arn_empty() {
         let arn1 = Arn::from_str("arn:aws:s3:::bucket").unwrap();
@@ -326,6 +1068,64 @@ mod test {
         assert_eq!(arn.region(), "ap-southeast-7-hòa-hiệp-bắc-3");
     }
 
+    #[test]
+    fn check_normalized() {
+        // "français" and "açores-1" with decomposed cedillas.
+        let partition = "franc\u{0327}ais";
+        let region = "ac\u{0327}ores-1";
+        assert_eq!(Arn::new(partition, "ec2", "", "", "x").unwrap_err(), ArnError::InvalidPartition(partition.into()));
+        assert_eq!(Arn::new("aws", "ec2", region, "", "x").unwrap_err(), ArnError::InvalidRegion(region.into()));
+
+        let arn1 = Arn::new_normalized(partition, "ec2", region, "123456789012", "instance/i-1").unwrap();
+        let arn2 = Arn::new("fran\u{00e7}ais", "ec2", "a\u{00e7}ores-1", "123456789012", "instance/i-1").unwrap();
+        assert_eq!(arn1.partition(), "fran\u{00e7}ais");
+        assert_eq!(arn1.region(), "a\u{00e7}ores-1");
+        assert_eq!(arn1, arn2);
+
+        let mut h1 = DefaultHasher::new();
+        let mut h2 = DefaultHasher::new();
+        arn1.hash(&mut h1);
+        arn2.hash(&mut h2);
+        assert_eq!(h1.finish(), h2.finish());
+
+        let s = format!("arn:{partition}:ec2:{region}:123456789012:instance/i-1");
+        assert!(Arn::from_str(&s).is_err());
+        assert_eq!(Arn::from_str_normalized(&s).unwrap(), arn2);
+
+        // Resources are not normalized.
+        let arn = Arn::from_str_normalized("arn:aws:s3:::c\u{0327}").unwrap();
+        assert_eq!(arn.resource(), "c\u{0327}");
+
+        // Normalization does not create new separators.
+        assert_eq!(
+            Arn::from_str_normalized("arn:aws\u{ff1a}iam::123456789012:user/x").unwrap_err(),
+            ArnError::InvalidArn("arn:aws\u{ff1a}iam::123456789012:user/x".into())
+        );
+        assert_eq!(
+            Arn::from_str_normalized("arn:aws\u{ff1a}iam:s3:::x").unwrap_err(),
+            ArnError::InvalidPartition("aws:iam".into())
+        );
+        assert_eq!(
+            Arn::new_normalized("aws\u{ff1a}iam", "s3", "", "", "x").unwrap_err(),
+            ArnError::InvalidPartition("aws:iam".into())
+        );
+        assert_eq!(
+            Arn::from_str_normalized("arn:aws:s3:us-east-1\u{fe55}123456789012:bucket").unwrap_err(),
+            ArnError::InvalidArn("arn:aws:s3:us-east-1\u{fe55}123456789012:bucket".into())
+        );
+        assert_eq!(
+            Arn::from_str_normalized("arn:aws:s3:us-east-1\u{fe55}x::bucket").unwrap_err(),
+            ArnError::InvalidRegion("us-east-1:x".into())
+        );
+
+        assert_eq!(Arn::from_str_normalized("arn:aws:s3").unwrap_err(), ArnError::InvalidArn("arn:aws:s3".into()));
+        assert_eq!(Arn::from_str_normalized("urn:aws:s3:::x").unwrap_err(), ArnError::InvalidScheme("urn".into()));
+        assert_eq!(
+            Arn::from_str_normalized("arn:aws:S\u{0327}:::x").unwrap_err(),
+            ArnError::InvalidService("\u{015e}".into())
+        );
+    }
+
     #[test]
     fn check_malformed_arns() {
         let wrong_parts =
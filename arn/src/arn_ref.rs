@@ -0,0 +1,483 @@
+use {
+    crate::{
+        arn::{split_resource, validate_arn_length, PARTITION_START},
+        utils::{check_account_id_digits, check_partition, check_region, check_resource, check_service, Diagnosis},
+        Arn, ArnComponent, ArnError, ArnErrorReason, ArnParseError, ScratchstackRelaxed, ValidationProfile,
+    },
+    alloc::string::ToString,
+    core::{
+        cmp::Ordering,
+        fmt::{Display, Formatter, Result as FmtResult},
+    },
+};
+
+/// A borrowed view of an Amazon Resource Name (ARN).
+///
+/// This applies the same validation rules as [Arn], but borrows the input string and stores only the offsets of each
+/// component. Parsing an [ArnRef] does not allocate, which makes it suitable for hot paths where most ARNs are
+/// inspected and then discarded. Use [ArnRef::to_owned] to obtain an [Arn] for ARNs that must be kept.
+///
+/// [ArnRef] objects are immutable.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ArnRef<'a> {
+    arn: &'a str,
+    service_start: usize,
+    region_start: usize,
+    account_id_start: usize,
+    resource_start: usize,
+}
+
+impl<'a> ArnRef<'a> {
+    /// Parse an ARN from a string without copying it.
+    ///
+    /// # Errors
+    ///
+    /// * If the ARN is not composed of 6 colon-separated components, [ArnError::InvalidArn] is returned.
+    /// * If the ARN does not start with `arn:`, [ArnError::InvalidScheme] is returned.
+    /// * If the partition is invalid, [ArnError::InvalidPartition] is returned.
+    /// * If the service is invalid, [ArnError::InvalidService] is returned.
+    /// * If the region is invalid, [ArnError::InvalidRegion] is returned.
+    /// * If the account ID is invalid, [ArnError::InvalidAccountId] is returned.
+    /// * If the resource is invalid or the ARN is too long, [ArnError::InvalidResource] is returned.
+    pub fn parse(s: &'a str) -> Result<Self, ArnError> {
+        Self::parse_with_profile(s, &ScratchstackRelaxed)
+    }
+
+    /// Parse an ARN from a string without copying it, validating the components according to the specified
+    /// [ValidationProfile].
+    ///
+    /// # Errors
+    ///
+    /// The errors are the same as for [ArnRef::parse].
+    pub fn parse_with_profile<P: ValidationProfile + ?Sized>(s: &'a str, profile: &P) -> Result<Self, ArnError> {
+        Self::parse_detailed_with_profile(s, profile).map_err(ArnError::from)
+    }
+
+    /// Parse an ARN from a string without copying it, returning a detailed [ArnParseError] on failure.
+    ///
+    /// The error records the component that failed, the byte range of the offending characters within `s`, and the
+    /// reason for the failure. It can be converted into the same [ArnError] that [ArnRef::parse] would return.
+    pub fn parse_detailed(s: &'a str) -> Result<Self, ArnParseError> {
+        Self::parse_detailed_with_profile(s, &ScratchstackRelaxed)
+    }
+
+    /// Parse an ARN from a string without copying it, validating the components according to the specified
+    /// [ValidationProfile] and returning a detailed [ArnParseError] on failure.
+    ///
+    /// If the profile rejects a component, the reason is diagnosed using the default rules in
+    /// [utils](crate::utils). If the component meets the default rules, the reason is
+    /// [ArnErrorReason::RejectedByProfile].
+    pub fn parse_detailed_with_profile<P: ValidationProfile + ?Sized>(
+        s: &'a str,
+        profile: &P,
+    ) -> Result<Self, ArnParseError> {
+        // Find the five colons separating the six components.
+        let mut colons = [0usize; 5];
+        let mut n_colons = 0;
+        for (i, b) in s.bytes().enumerate() {
+            if b == b':' {
+                colons[n_colons] = i;
+                n_colons += 1;
+                if n_colons == 5 {
+                    break;
+                }
+            }
+        }
+
+        if n_colons != 5 {
+            let reason = ArnErrorReason::MissingComponents(n_colons + 1);
+            return Err(ArnParseError::new(s, ArnComponent::Arn, 0..s.len(), s.len()..s.len(), reason));
+        }
+
+        let scheme = &s[..colons[0]];
+        if scheme != "arn" {
+            let span = 0..colons[0];
+            return Err(ArnParseError::new(s, ArnComponent::Scheme, span.clone(), span, ArnErrorReason::InvalidScheme));
+        }
+
+        let result = Self {
+            arn: s,
+            service_start: colons[1] + 1,
+            region_start: colons[2] + 1,
+            account_id_start: colons[3] + 1,
+            resource_start: colons[4] + 1,
+        };
+
+        // Convert a profile failure into a detailed error for the component starting at `start`.
+        let fail = |component: ArnComponent, start: usize, value: &str, diagnosis: Result<(), Diagnosis>| {
+            let component_span = start..start + value.len();
+            match diagnosis {
+                Err(d) => {
+                    ArnParseError::new(s, component, component_span, start + d.span.start..start + d.span.end, d.reason)
+                }
+                Ok(()) => ArnParseError::new(
+                    s,
+                    component,
+                    component_span.clone(),
+                    component_span,
+                    ArnErrorReason::RejectedByProfile,
+                ),
+            }
+        };
+
+        let partition = result.partition();
+        if profile.validate_partition(partition).is_err() {
+            return Err(fail(ArnComponent::Partition, PARTITION_START, partition, check_partition(partition)));
+        }
+
+        let service = result.service();
+        if profile.validate_service(service).is_err() {
+            return Err(fail(ArnComponent::Service, result.service_start, service, check_service(service)));
+        }
+
+        let region = result.region();
+        if !region.is_empty() && profile.validate_region(region).is_err() {
+            return Err(fail(ArnComponent::Region, result.region_start, region, check_region(region)));
+        }
+
+        let account_id = result.account_id();
+        if !account_id.is_empty() && profile.validate_account_id(account_id).is_err() {
+            let diagnosis = check_account_id_digits(account_id, 12);
+            return Err(fail(ArnComponent::AccountId, result.account_id_start, account_id, diagnosis));
+        }
+
+        let resource = result.resource();
+        if profile.validate_resource(resource).is_err() {
+            return Err(fail(ArnComponent::Resource, result.resource_start, resource, check_resource(resource)));
+        }
+
+        if validate_arn_length(profile, &[s], resource).is_err() {
+            // validate_arn_length only fails if there is a maximum length and the ARN has more characters than it.
+            let max_length = profile.max_arn_length().unwrap_or_default();
+            let excess_start = s.char_indices().nth(max_length).map(|(i, _)| i).unwrap_or(s.len());
+            return Err(ArnParseError::new(
+                s,
+                ArnComponent::Resource,
+                result.resource_start..s.len(),
+                excess_start..s.len(),
+                ArnErrorReason::TooLong(max_length),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Create a view from an ARN string and its component offsets, bypassing any validation.
+    ///
+    /// # Safety
+    ///
+    /// `arn` must be a valid ARN string, and the offsets must point to the first byte of the corresponding component
+    /// within `arn`.
+    pub(crate) unsafe fn from_parts_unchecked(
+        arn: &'a str,
+        service_start: usize,
+        region_start: usize,
+        account_id_start: usize,
+        resource_start: usize,
+    ) -> Self {
+        Self {
+            arn,
+            service_start,
+            region_start,
+            account_id_start,
+            resource_start,
+        }
+    }
+
+    /// Retrieve the partition the resource is in.
+    #[inline]
+    pub fn partition(&self) -> &'a str {
+        &self.arn[PARTITION_START..self.service_start - 1]
+    }
+
+    /// Retrieve the service the resource belongs to.
+    #[inline]
+    pub fn service(&self) -> &'a str {
+        &self.arn[self.service_start..self.region_start - 1]
+    }
+
+    /// Retrieve the region the resource is in.
+    #[inline]
+    pub fn region(&self) -> &'a str {
+        &self.arn[self.region_start..self.account_id_start - 1]
+    }
+
+    /// Retrieve the account ID the resource belongs to.
+    #[inline]
+    pub fn account_id(&self) -> &'a str {
+        &self.arn[self.account_id_start..self.resource_start - 1]
+    }
+
+    /// Retrieve the resource name.
+    #[inline]
+    pub fn resource(&self) -> &'a str {
+        &self.arn[self.resource_start..]
+    }
+
+    /// Retrieve the resource type. See [Arn::resource_type].
+    #[inline]
+    pub fn resource_type(&self) -> Result<&'a str, ArnError> {
+        split_resource(self.resource()).map(|(resource_type, _, _)| resource_type)
+    }
+
+    /// Retrieve the resource id. See [Arn::resource_id].
+    #[inline]
+    pub fn resource_id(&self) -> Result<&'a str, ArnError> {
+        split_resource(self.resource()).map(|(_, resource_id, _)| resource_id)
+    }
+
+    /// Retrieve the resource qualifier, if any. See [Arn::resource_qualifier].
+    #[inline]
+    pub fn resource_qualifier(&self) -> Result<Option<&'a str>, ArnError> {
+        split_resource(self.resource()).map(|(_, _, qualifier)| qualifier)
+    }
+
+    /// Retrieve the entire ARN string this view borrows.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.arn
+    }
+
+    /// Copy this view into an owned [Arn].
+    pub fn to_owned(&self) -> Arn {
+        // Safety: The components were validated when this view was parsed.
+        unsafe {
+            Arn::from_parts_unchecked(
+                self.arn.to_string(),
+                self.service_start,
+                self.region_start,
+                self.account_id_start,
+                self.resource_start,
+            )
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ArnRef<'a> {
+    type Error = ArnError;
+
+    /// Parse an ARN from a string without copying it. See [ArnRef::parse].
+    fn try_from(s: &'a str) -> Result<Self, ArnError> {
+        Self::parse(s)
+    }
+}
+
+impl<'a> From<&'a Arn> for ArnRef<'a> {
+    fn from(arn: &'a Arn) -> Self {
+        arn.as_arn_ref()
+    }
+}
+
+impl From<ArnRef<'_>> for Arn {
+    fn from(arn: ArnRef<'_>) -> Self {
+        arn.to_owned()
+    }
+}
+
+impl Display for ArnRef<'_> {
+    /// Return the ARN.
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.arn)
+    }
+}
+
+impl PartialEq<Arn> for ArnRef<'_> {
+    fn eq(&self, other: &Arn) -> bool {
+        *self == other.as_arn_ref()
+    }
+}
+
+impl PartialEq<ArnRef<'_>> for Arn {
+    fn eq(&self, other: &ArnRef<'_>) -> bool {
+        self.as_arn_ref() == *other
+    }
+}
+
+/// Orders ARNs by partition, service, region, account ID, and resource.
+impl PartialOrd for ArnRef<'_> {
+    /// Returns the relative ordering between this and another ARN.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders ARNs by partition, service, region, account ID, and resource.
+impl Ord for ArnRef<'_> {
+    /// Returns the relative ordering between this and another ARN.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.partition()
+            .cmp(other.partition())
+            .then_with(|| self.service().cmp(other.service()))
+            .then_with(|| self.region().cmp(other.region()))
+            .then_with(|| self.account_id().cmp(other.account_id()))
+            .then_with(|| self.resource().cmp(other.resource()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::ArnRef,
+        crate::{Arn, ArnComponent, ArnError, ArnErrorReason, AwsStrict, LegacyResources, ScratchstackRelaxed},
+        pretty_assertions::assert_eq,
+        std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+            str::FromStr,
+        },
+    };
+
+    #[test]
+    fn check_components() {
+        let s = "arn:aws:ec2:us-east-1:123456789012:instance/i-1234567890abcdef0";
+        let arn_ref = ArnRef::parse(s).unwrap();
+        assert_eq!(arn_ref.partition(), "aws");
+        assert_eq!(arn_ref.service(), "ec2");
+        assert_eq!(arn_ref.region(), "us-east-1");
+        assert_eq!(arn_ref.account_id(), "123456789012");
+        assert_eq!(arn_ref.resource(), "instance/i-1234567890abcdef0");
+        assert_eq!(arn_ref.as_str(), s);
+        assert_eq!(arn_ref.to_string(), s);
+        assert!(std::ptr::eq(arn_ref.as_str(), s));
+
+        let arn_ref = ArnRef::try_from("arn:aws:logs:us-east-1:123456789012:log-group:/aws/x:*").unwrap();
+        assert_eq!(arn_ref.account_id(), "123456789012");
+        assert_eq!(arn_ref.resource(), "log-group:/aws/x:*");
+        assert_eq!(arn_ref.resource_type().unwrap(), "log-group");
+        assert_eq!(arn_ref.resource_id().unwrap(), "/aws/x");
+        assert_eq!(arn_ref.resource_qualifier().unwrap(), Some("*"));
+
+        let arn_ref = ArnRef::parse("arn:aws:s3:::bucket").unwrap();
+        assert_eq!(arn_ref.region(), "");
+        assert_eq!(arn_ref.account_id(), "");
+        assert_eq!(arn_ref.resource(), "bucket");
+        assert_eq!(arn_ref.resource_type().unwrap_err(), ArnError::InvalidResource("bucket".to_string()));
+    }
+
+    #[test]
+    fn check_owned_conversion() {
+        let s = "arn:aws-中国:één:日本-東京-1:123456789012:instance/i-1234567890abcdef0";
+        let arn_ref = ArnRef::parse(s).unwrap();
+        let arn = arn_ref.to_owned();
+        assert_eq!(arn, Arn::from_str(s).unwrap());
+        assert_eq!(arn.partition(), arn_ref.partition());
+        assert_eq!(arn.service(), arn_ref.service());
+        assert_eq!(arn.region(), arn_ref.region());
+        assert_eq!(arn.account_id(), arn_ref.account_id());
+        assert_eq!(arn.resource(), arn_ref.resource());
+        assert_eq!(arn, arn_ref);
+        assert_eq!(arn_ref, arn);
+        assert_eq!(ArnRef::from(&arn), arn_ref);
+        assert_eq!(Arn::from(arn_ref), arn);
+
+        // Hashes agree with the owned representation.
+        let mut h1 = DefaultHasher::new();
+        let mut h2 = DefaultHasher::new();
+        arn.hash(&mut h1);
+        arn_ref.hash(&mut h2);
+        assert_eq!(h1.finish(), h2.finish());
+    }
+
+    #[test]
+    fn check_ordering() {
+        let a1 = ArnRef::parse("arn:aws:ec2:us-east-1:123456789012:instance/i-0").unwrap();
+        let a2 = ArnRef::parse("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        let a3 = ArnRef::parse("arn:aws:ec2:us-east-2:123456789012:instance/i-0").unwrap();
+        let a4 = ArnRef::parse("arn:aws:s3:::bucket").unwrap();
+        assert!(a1 < a2);
+        assert!(a2 < a3);
+        assert!(a3 < a4);
+        assert_eq!(a1.cmp(&a1), std::cmp::Ordering::Equal);
+        assert_eq!(a1.partial_cmp(&a2), Some(std::cmp::Ordering::Less));
+        assert_eq!(a1.to_owned().cmp(&a2.to_owned()), a1.cmp(&a2));
+        let _ = format!("{a1:?}");
+    }
+
+    #[test]
+    fn check_errors() {
+        for s in ["arn", "arn:aws", "arn:aws:ec2", "arn:aws:ec2:us-east-1", "arn:aws:ec2:us-east-1:123456789012"] {
+            assert_eq!(ArnRef::parse(s).unwrap_err(), ArnError::InvalidArn(s.to_string()));
+            assert_eq!(ArnRef::parse(s).unwrap_err(), Arn::from_str(s).unwrap_err());
+        }
+
+        assert_eq!(ArnRef::parse("http:aws:ec2::123456789012:x").unwrap_err(), ArnError::InvalidScheme("http".into()));
+        assert_eq!(ArnRef::parse("arn:Aws:ec2::123456789012:x").unwrap_err(), ArnError::InvalidPartition("Aws".into()));
+        assert_eq!(ArnRef::parse("arn:aws:Ec2::123456789012:x").unwrap_err(), ArnError::InvalidService("Ec2".into()));
+        assert_eq!(ArnRef::parse("arn:aws:ec2:us-east::x").unwrap_err(), ArnError::InvalidRegion("us-east".into()));
+        assert_eq!(ArnRef::parse("arn:aws:ec2::1234:x").unwrap_err(), ArnError::InvalidAccountId("1234".into()));
+    }
+
+    #[test]
+    fn check_detailed_errors() {
+        let err = ArnRef::parse_detailed("arn:aws:ec2:us-east1:123456789012:instance/i-1").unwrap_err();
+        assert_eq!(err.component(), ArnComponent::Region);
+        assert_eq!(err.component_span(), 12..20);
+        assert_eq!(err.component_str(), "us-east1");
+        assert_eq!(err.span(), 19..20);
+        assert_eq!(err.reason(), &ArnErrorReason::UnexpectedCharacter('1'));
+        assert_eq!(err.input(), "arn:aws:ec2:us-east1:123456789012:instance/i-1");
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east1": unexpected character '1' at byte 19"#);
+        assert_eq!(
+            format!("{err:#}"),
+            concat!(
+                "Invalid region: \"us-east1\": unexpected character '1' at byte 19\n",
+                "  arn:aws:ec2:us-east1:123456789012:instance/i-1\n",
+                "                     ^",
+            )
+        );
+        assert_eq!(ArnError::from(err), ArnError::InvalidRegion("us-east1".into()));
+
+        let err = Arn::from_str_detailed("arn:aws:ec2:us-east-1").unwrap_err();
+        assert_eq!(err.component(), ArnComponent::Arn);
+        assert_eq!(err.span(), 21..21);
+        assert_eq!(err.reason(), &ArnErrorReason::MissingComponents(4));
+        assert_eq!(ArnError::from(err), ArnError::InvalidArn("arn:aws:ec2:us-east-1".into()));
+
+        let err = ArnRef::parse_detailed("http:aws:ec2::123456789012:x").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::Scheme, 0..4));
+        assert_eq!(err.reason(), &ArnErrorReason::InvalidScheme);
+        assert_eq!(format!("{err:#}").lines().last().unwrap(), "  ^^^^");
+
+        let err = ArnRef::parse_detailed("arn:aws--cn:ec2::123456789012:x").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::Partition, 8..9));
+        assert_eq!(err.reason(), &ArnErrorReason::ConsecutiveDashes);
+
+        let err = ArnRef::parse_detailed("arn:aws::::x").unwrap_err();
+        assert_eq!((err.component(), err.component_span(), err.span()), (ArnComponent::Service, 8..8, 8..8));
+        assert_eq!(err.reason(), &ArnErrorReason::Empty);
+
+        let err = ArnRef::parse_detailed("arn:aws:ec2:us-east::x").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::Region, 19..19));
+        assert_eq!(err.reason(), &ArnErrorReason::IncompleteRegion);
+
+        let err = ArnRef::parse_detailed("arn:aws:ec2::12345678901x:x").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::AccountId, 24..25));
+        assert_eq!(err.reason(), &ArnErrorReason::InvalidCharacter('x'));
+
+        let err = ArnRef::parse_detailed("arn:aws:ec2::1234:x").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::AccountId, 13..17));
+        assert_eq!(err.reason(), &ArnErrorReason::InvalidLength);
+
+        // Control characters are replaced in the rendered input so the caret still lines up.
+        let err = ArnRef::parse_detailed("arn:aws:s3:::a\tb").unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::Resource, 14..15));
+        assert_eq!(err.reason(), &ArnErrorReason::InvalidCharacter('\t'));
+        assert_eq!(format!("{err:#}").lines().nth(1).unwrap(), "  arn:aws:s3:::a\u{fffd}b");
+        assert_eq!(ArnError::from(err), ArnError::InvalidResource("a\tb".into()));
+
+        // Components the default rules accept, but the profile does not.
+        let err = ArnRef::parse_detailed_with_profile("arn:aws:ec2:local::x", &AwsStrict).unwrap_err();
+        assert_eq!((err.component(), err.span()), (ArnComponent::Region, 12..17));
+        assert_eq!(err.reason(), &ArnErrorReason::RejectedByProfile);
+
+        let long = format!("arn:aws:s3:::{}", "é".repeat(2040));
+        let err = Arn::from_str_detailed_with_profile(&long, &ScratchstackRelaxed).unwrap_err();
+        assert_eq!(err.component(), ArnComponent::Resource);
+        assert_eq!(err.component_span(), 13..long.len());
+        assert_eq!(err.span(), 13 + 2 * (2048 - 13)..long.len());
+        assert_eq!(err.reason(), &ArnErrorReason::TooLong(2048));
+        assert_eq!(format!("{err:#}").lines().last().unwrap(), format!("  {}^^^^^", " ".repeat(2048)));
+        Arn::from_str_detailed_with_profile(&long, &LegacyResources::<ScratchstackRelaxed>::default()).unwrap();
+    }
+}
+// end tests -- do not delete; needed for coverage.
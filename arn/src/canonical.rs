@@ -0,0 +1,258 @@
+use {
+    crate::Arn,
+    alloc::{borrow::Cow, string::String},
+    core::{
+        cmp::Ordering,
+        fmt::{Display, Formatter, Result as FmtResult},
+        hash::{Hash, Hasher},
+        ops::Deref,
+    },
+};
+
+/// IAM resource types whose names are case-insensitive. The name is the last `/`-separated segment; the path is
+/// case-sensitive.
+const IAM_CASE_INSENSITIVE_TYPES: &[&str] =
+    &["group", "instance-profile", "policy", "role", "server-certificate", "user"];
+
+/// An [Arn] that compares, orders, and hashes using the case rules of its service.
+///
+/// [Arn] compares resources byte-for-byte, but some services treat names as case-insensitive. IAM user, group, role,
+/// instance profile, policy, and server certificate names are case-insensitive, so
+/// `arn:aws:iam::123456789012:role/Admin` and `arn:aws:iam::123456789012:role/admin` are the same entity.
+/// [CanonicalArn] folds these names to ASCII lowercase before comparing, which lets IAM entities be deduplicated in
+/// maps and sets.
+///
+/// The rules applied are:
+/// * `iam`: For the resource types above, the name (the last `/`-separated segment) is case-insensitive. Paths are
+///   case-sensitive.
+/// * `sts`: The role name in `assumed-role/role-name/session-name` and the user name in `federated-user/user-name` are
+///   case-insensitive.
+///
+/// All other resources, and all other components, are compared exactly. The original ARN is preserved and is what is
+/// displayed.
+#[derive(Clone, Debug)]
+pub struct CanonicalArn {
+    arn: Arn,
+
+    /// The resource with case-insensitive portions folded, or `None` if this is the same as the resource.
+    folded_resource: Option<String>,
+}
+
+impl CanonicalArn {
+    /// Wrap `arn`, computing its canonical resource.
+    pub fn new(arn: Arn) -> Self {
+        let folded_resource = match fold_resource(arn.service(), arn.resource()) {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(folded) => Some(folded),
+        };
+
+        Self {
+            arn,
+            folded_resource,
+        }
+    }
+
+    /// Retrieve the original ARN.
+    #[inline]
+    pub fn arn(&self) -> &Arn {
+        &self.arn
+    }
+
+    /// Unwrap the original ARN.
+    #[inline]
+    pub fn into_arn(self) -> Arn {
+        self.arn
+    }
+
+    /// Retrieve the resource with case-insensitive portions folded to lowercase. This is the resource used for
+    /// comparisons.
+    #[inline]
+    pub fn canonical_resource(&self) -> &str {
+        self.folded_resource.as_deref().unwrap_or_else(|| self.arn.resource())
+    }
+
+    /// The components used for comparison, in order.
+    fn key(&self) -> (&str, &str, &str, &str, &str) {
+        (self.arn.partition(), self.arn.service(), self.arn.region(), self.arn.account_id(), self.canonical_resource())
+    }
+}
+
+/// Fold the case-insensitive portions of `resource` for `service` to ASCII lowercase, borrowing if nothing changes.
+fn fold_resource<'a>(service: &str, resource: &'a str) -> Cow<'a, str> {
+    let Some((resource_type, rest)) = resource.split_once('/') else {
+        return Cow::Borrowed(resource);
+    };
+
+    // The byte range of the resource that is case-insensitive.
+    let start = resource_type.len() + 1;
+    let range = match (service, resource_type) {
+        ("iam", t) if IAM_CASE_INSENSITIVE_TYPES.contains(&t) => match rest.rfind('/') {
+            Some(pos) => start + pos + 1..resource.len(),
+            None => start..resource.len(),
+        },
+        ("sts", "assumed-role") => match rest.find('/') {
+            Some(pos) => start..start + pos,
+            None => start..resource.len(),
+        },
+        ("sts", "federated-user") => start..resource.len(),
+        _ => return Cow::Borrowed(resource),
+    };
+
+    let name = &resource[range.clone()];
+    if !name.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Cow::Borrowed(resource);
+    }
+
+    let mut folded = String::with_capacity(resource.len());
+    folded.push_str(&resource[..range.start]);
+    folded.push_str(&name.to_ascii_lowercase());
+    folded.push_str(&resource[range.end..]);
+    Cow::Owned(folded)
+}
+
+impl AsRef<Arn> for CanonicalArn {
+    fn as_ref(&self) -> &Arn {
+        &self.arn
+    }
+}
+
+impl Deref for CanonicalArn {
+    type Target = Arn;
+
+    fn deref(&self) -> &Arn {
+        &self.arn
+    }
+}
+
+impl Display for CanonicalArn {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.arn, f)
+    }
+}
+
+impl From<Arn> for CanonicalArn {
+    fn from(arn: Arn) -> Self {
+        Self::new(arn)
+    }
+}
+
+impl From<CanonicalArn> for Arn {
+    fn from(canonical: CanonicalArn) -> Self {
+        canonical.arn
+    }
+}
+
+impl PartialEq for CanonicalArn {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for CanonicalArn {}
+
+/// Hashes the partition, service, region, account ID, and canonical resource.
+impl Hash for CanonicalArn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+/// Orders ARNs by partition, service, region, account ID, and canonical resource.
+impl PartialOrd for CanonicalArn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders ARNs by partition, service, region, account ID, and canonical resource.
+impl Ord for CanonicalArn {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::CanonicalArn,
+        crate::Arn,
+        pretty_assertions::assert_eq,
+        std::{
+            collections::{BTreeSet, HashSet},
+            str::FromStr,
+        },
+    };
+
+    fn canonical(s: &str) -> CanonicalArn {
+        CanonicalArn::new(Arn::from_str(s).unwrap())
+    }
+
+    #[test]
+    fn check_iam() {
+        let a = canonical("arn:aws:iam::123456789012:role/Admin");
+        let b = canonical("arn:aws:iam::123456789012:role/admin");
+        assert_eq!(a, b);
+        assert_eq!(a.canonical_resource(), "role/admin");
+        assert_eq!(a.to_string(), "arn:aws:iam::123456789012:role/Admin");
+        assert_eq!(b.canonical_resource(), "role/admin");
+
+        // Only the name is case-insensitive.
+        let a = canonical("arn:aws:iam::123456789012:user/Division/Alice");
+        assert_eq!(a.canonical_resource(), "user/Division/alice");
+        assert_ne!(a, canonical("arn:aws:iam::123456789012:user/division/alice"));
+        assert_eq!(a, canonical("arn:aws:iam::123456789012:user/Division/ALICE"));
+
+        for resource in ["group/Dev", "instance-profile/Web", "policy/ReadOnly", "server-certificate/Cert"] {
+            let upper = canonical(&format!("arn:aws:iam::123456789012:{resource}"));
+            let lower = canonical(&format!("arn:aws:iam::123456789012:{}", resource.to_ascii_lowercase()));
+            assert_eq!(upper, lower);
+        }
+
+        // Other components and other resource types are exact.
+        assert_ne!(canonical("arn:aws:iam::123456789012:mfa/Alice"), canonical("arn:aws:iam::123456789012:mfa/alice"));
+        assert_ne!(canonical("arn:aws:iam::123456789012:role/A"), canonical("arn:aws:iam::123456789013:role/a"));
+        assert_ne!(canonical("arn:aws:s3:::Bucket"), canonical("arn:aws:s3:::bucket"));
+        assert_ne!(
+            canonical("arn:aws:ec2:us-east-1:123456789012:role/A"),
+            canonical("arn:aws:ec2:us-east-1:123456789012:role/a")
+        );
+        assert_eq!(canonical("arn:aws:iam::123456789012:root").canonical_resource(), "root");
+    }
+
+    #[test]
+    fn check_sts() {
+        let a = canonical("arn:aws:sts::123456789012:assumed-role/Admin/Session");
+        assert_eq!(a.canonical_resource(), "assumed-role/admin/Session");
+        assert_eq!(a, canonical("arn:aws:sts::123456789012:assumed-role/ADMIN/Session"));
+        assert_ne!(a, canonical("arn:aws:sts::123456789012:assumed-role/Admin/session"));
+
+        assert_eq!(
+            canonical("arn:aws:sts::123456789012:federated-user/Bob"),
+            canonical("arn:aws:sts::123456789012:federated-user/bob")
+        );
+    }
+
+    #[test]
+    fn check_collections() {
+        let arns = [
+            "arn:aws:iam::123456789012:role/Admin",
+            "arn:aws:iam::123456789012:role/admin",
+            "arn:aws:iam::123456789012:role/Reader",
+            "arn:aws:iam::123456789012:user/Admin",
+        ];
+
+        let hashed: HashSet<_> = arns.iter().map(|s| canonical(s)).collect();
+        assert_eq!(hashed.len(), 3);
+
+        let sorted: BTreeSet<_> = arns.iter().map(|s| canonical(s)).collect();
+        let sorted: Vec<_> = sorted.iter().map(|c| c.canonical_resource()).collect();
+        assert_eq!(sorted, vec!["role/admin", "role/reader", "user/admin"]);
+
+        let c = canonical(arns[0]);
+        assert_eq!(c.partition(), "aws");
+        assert_eq!(c.arn(), &Arn::from_str(arns[0]).unwrap());
+        assert_eq!(Arn::from(c.clone()), c.into_arn());
+        assert_eq!(CanonicalArn::from(Arn::from_str(arns[1]).unwrap()), canonical(arns[0]));
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -1,6 +1,10 @@
-use std::{
-    error::Error,
-    fmt::{Debug, Display, Formatter, Result as FmtResult},
+use {
+    alloc::string::{String, ToString},
+    core::{
+        error::Error,
+        fmt::{Debug, Display, Formatter, Result as FmtResult},
+        ops::Range,
+    },
 };
 
 /// Errors that can be raise during the parsing of ARNs.
@@ -44,13 +48,231 @@ impl Display for ArnError {
     }
 }
 
+/// A component of an ARN, used to report where parsing failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ArnComponent {
+    /// The ARN as a whole; used when the ARN does not have six colon-separated components.
+    Arn,
+
+    /// The scheme, which must be `arn`.
+    Scheme,
+
+    /// The partition.
+    Partition,
+
+    /// The service.
+    Service,
+
+    /// The region.
+    Region,
+
+    /// The account id.
+    AccountId,
+
+    /// The resource.
+    Resource,
+}
+
+impl Display for ArnComponent {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Arn => f.write_str("ARN"),
+            Self::Scheme => f.write_str("scheme"),
+            Self::Partition => f.write_str("partition"),
+            Self::Service => f.write_str("service"),
+            Self::Region => f.write_str("region"),
+            Self::AccountId => f.write_str("account id"),
+            Self::Resource => f.write_str("resource"),
+        }
+    }
+}
+
+/// The reason an ARN component failed validation.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ArnErrorReason {
+    /// The ARN does not have six colon-separated components. The argument contains the number found.
+    MissingComponents(usize),
+
+    /// The scheme is not `arn`.
+    InvalidScheme,
+
+    /// The component is empty.
+    Empty,
+
+    /// The component contains a character that is never allowed in it.
+    InvalidCharacter(char),
+
+    /// The component contains a character that is allowed, but not in this position.
+    UnexpectedCharacter(char),
+
+    /// The component starts with `-`.
+    LeadingDash,
+
+    /// The component ends with `-`.
+    TrailingDash,
+
+    /// The component contains two consecutive `-` characters.
+    ConsecutiveDashes,
+
+    /// The component (or, for the resource, the entire ARN) exceeds the maximum length. The argument contains the
+    /// maximum length.
+    TooLong(usize),
+
+    /// The component has the wrong length.
+    InvalidLength,
+
+    /// The region does not end with `-` followed by one or more digits.
+    IncompleteRegion,
+
+    /// The component meets the default rules, but was rejected by the [ValidationProfile](crate::ValidationProfile)
+    /// in use.
+    RejectedByProfile,
+}
+
+impl Display for ArnErrorReason {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::MissingComponents(found) => write!(f, "expected 6 colon-separated components, found {found}"),
+            Self::InvalidScheme => f.write_str(r#"scheme must be "arn""#),
+            Self::Empty => f.write_str("must not be empty"),
+            Self::InvalidCharacter(c) => write!(f, "invalid character {c:?}"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character {c:?}"),
+            Self::LeadingDash => f.write_str("cannot start with '-'"),
+            Self::TrailingDash => f.write_str("cannot end with '-'"),
+            Self::ConsecutiveDashes => f.write_str("cannot contain consecutive '-' characters"),
+            Self::TooLong(max) => write!(f, "exceeds the maximum length of {max}"),
+            Self::InvalidLength => f.write_str("has an invalid length"),
+            Self::IncompleteRegion => f.write_str("must end with '-' followed by digits"),
+            Self::RejectedByProfile => f.write_str("rejected by the validation profile"),
+        }
+    }
+}
+
+/// A detailed error raised while parsing an ARN, recording where in the input the error occurred.
+///
+/// The [Display] implementation renders a one-line message. With the alternate flag (`{:#}`), the input is also
+/// rendered with a caret (`^`) marking the offending characters:
+///
+/// ```text
+/// Invalid region: "us-east1": unexpected character '1' at byte 19
+///   arn:aws:ec2:us-east1:123456789012:instance/i-1
+///                      ^
+/// ```
+///
+/// An [ArnParseError] can be converted into the equivalent [ArnError].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ArnParseError {
+    input: String,
+    component: ArnComponent,
+    component_span: Range<usize>,
+    span: Range<usize>,
+    reason: ArnErrorReason,
+}
+
+impl ArnParseError {
+    pub(crate) fn new(
+        input: &str,
+        component: ArnComponent,
+        component_span: Range<usize>,
+        span: Range<usize>,
+        reason: ArnErrorReason,
+    ) -> Self {
+        Self {
+            input: input.to_string(),
+            component,
+            component_span,
+            span,
+            reason,
+        }
+    }
+
+    /// The input that failed to parse.
+    #[inline]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The component that failed to parse.
+    #[inline]
+    pub fn component(&self) -> ArnComponent {
+        self.component
+    }
+
+    /// The byte range of the failed component within the input.
+    #[inline]
+    pub fn component_span(&self) -> Range<usize> {
+        self.component_span.clone()
+    }
+
+    /// The byte range of the offending characters within the input. This may be empty if the error is due to
+    /// something missing (e.g. an empty component).
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The reason the component failed to parse.
+    #[inline]
+    pub fn reason(&self) -> &ArnErrorReason {
+        &self.reason
+    }
+
+    /// The text of the failed component.
+    #[inline]
+    pub fn component_str(&self) -> &str {
+        &self.input[self.component_span.clone()]
+    }
+}
+
+impl Error for ArnParseError {}
+
+impl Display for ArnParseError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}: {} at byte {}", ArnError::from(self.clone()), self.reason, self.span.start)?;
+
+        if f.alternate() {
+            // Replace control characters so the caret lines up with the rendered input.
+            let rendered: String = self
+                .input
+                .chars()
+                .map(|c| {
+                    if c.is_control() {
+                        '\u{fffd}'
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+            let column = self.input[..self.span.start].chars().count();
+            let width = self.input[self.span.clone()].chars().count().max(1);
+            write!(f, "\n  {rendered}\n  {}{}", " ".repeat(column), "^".repeat(width))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ArnParseError> for ArnError {
+    fn from(err: ArnParseError) -> Self {
+        match err.component {
+            ArnComponent::Arn => Self::InvalidArn(err.input),
+            ArnComponent::Scheme => Self::InvalidScheme(err.component_str().to_string()),
+            ArnComponent::Partition => Self::InvalidPartition(err.component_str().to_string()),
+            ArnComponent::Service => Self::InvalidService(err.component_str().to_string()),
+            ArnComponent::Region => Self::InvalidRegion(err.component_str().to_string()),
+            ArnComponent::AccountId => Self::InvalidAccountId(err.component_str().to_string()),
+            ArnComponent::Resource => Self::InvalidResource(err.component_str().to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ArnError;
 
     #[test]
     fn check_derived() {
-        let errors = vec![
+        let errors = [
             ArnError::InvalidAccountId("1234".to_string()),
             ArnError::InvalidArn("arn:aws:iam::1234:role/role-name".to_string()),
             ArnError::InvalidPartition("aws".to_string()),
@@ -76,7 +298,6 @@ mod tests {
 
     #[test]
     fn check_resource() {
-        // We do not construct InvalidResource currently.
         let err = ArnError::InvalidResource("".to_string());
         assert_eq!(err.to_string().as_str(), "Invalid resource: \"\"");
     }
@@ -0,0 +1,424 @@
+use {
+    crate::{Arn, ArnError, ArnRef},
+    std::{
+        borrow::Borrow,
+        cmp::Ordering,
+        collections::{HashMap, HashSet},
+        fmt::{Debug, Display, Formatter, Result as FmtResult},
+        hash::{Hash, Hasher},
+        sync::{
+            atomic::{AtomicU64, Ordering as AtomicOrdering},
+            Arc,
+        },
+    },
+};
+
+/// Source of unique ids for interners.
+static NEXT_INTERNER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The `arn:partition:service:region:account-id:` prefix shared by ARNs in the same scope.
+struct Scope {
+    prefix: Box<str>,
+    service_start: usize,
+    region_start: usize,
+    account_id_start: usize,
+}
+
+struct Inner {
+    scope: Arc<Scope>,
+    resource: Box<str>,
+    interner_id: u64,
+}
+
+/// An ARN interned by an [ArnInterner].
+///
+/// Cloning an interned ARN copies a pointer. ARNs with the same partition, service, region, and account id share a
+/// single copy of that prefix, and identical ARNs share all of their storage. Comparing two ARNs interned by the same
+/// interner for equality is O(1); ARNs from different interners are compared by value.
+///
+/// Interned ARNs are ordered the same way as [Arn].
+#[derive(Clone)]
+pub struct InternedArn {
+    inner: Arc<Inner>,
+}
+
+impl InternedArn {
+    /// Retrieve the partition the resource is in.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        let scope = &self.inner.scope;
+        &scope.prefix[4..scope.service_start - 1]
+    }
+
+    /// Retrieve the service the resource belongs to.
+    #[inline]
+    pub fn service(&self) -> &str {
+        let scope = &self.inner.scope;
+        &scope.prefix[scope.service_start..scope.region_start - 1]
+    }
+
+    /// Retrieve the region the resource is in.
+    #[inline]
+    pub fn region(&self) -> &str {
+        let scope = &self.inner.scope;
+        &scope.prefix[scope.region_start..scope.account_id_start - 1]
+    }
+
+    /// Retrieve the account ID the resource belongs to.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        let scope = &self.inner.scope;
+        &scope.prefix[scope.account_id_start..scope.prefix.len() - 1]
+    }
+
+    /// Retrieve the resource name.
+    #[inline]
+    pub fn resource(&self) -> &str {
+        &self.inner.resource
+    }
+
+    /// Convert this into an [Arn].
+    pub fn to_arn(&self) -> Arn {
+        // Safety: The components were taken from a valid ARN when interned.
+        unsafe {
+            Arn::new_unchecked(self.partition(), self.service(), self.region(), self.account_id(), self.resource())
+        }
+    }
+
+    /// Indicates whether `self` and `other` are the same interned ARN.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Debug for InternedArn {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("InternedArn")
+            .field("partition", &self.partition())
+            .field("service", &self.service())
+            .field("region", &self.region())
+            .field("account_id", &self.account_id())
+            .field("resource", &self.resource())
+            .finish()
+    }
+}
+
+impl Display for InternedArn {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(&self.inner.scope.prefix)?;
+        f.write_str(&self.inner.resource)
+    }
+}
+
+impl PartialEq for InternedArn {
+    fn eq(&self, other: &Self) -> bool {
+        if self.ptr_eq(other) {
+            true
+        } else if self.inner.interner_id == other.inner.interner_id {
+            // The interner never creates two copies of the same ARN.
+            false
+        } else {
+            self.inner.scope.prefix == other.inner.scope.prefix && self.inner.resource == other.inner.resource
+        }
+    }
+}
+
+impl Eq for InternedArn {}
+
+/// Hashes the ARN string, so ARNs that compare equal across interners hash the same way.
+impl Hash for InternedArn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.scope.prefix.hash(state);
+        self.inner.resource.hash(state);
+    }
+}
+
+impl PartialOrd for InternedArn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders ARNs by partition, service, region, account ID, and resource.
+impl Ord for InternedArn {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.ptr_eq(other) {
+            return Ordering::Equal;
+        }
+
+        self.partition()
+            .cmp(other.partition())
+            .then_with(|| self.service().cmp(other.service()))
+            .then_with(|| self.region().cmp(other.region()))
+            .then_with(|| self.account_id().cmp(other.account_id()))
+            .then_with(|| self.resource().cmp(other.resource()))
+    }
+}
+
+impl PartialEq<Arn> for InternedArn {
+    fn eq(&self, other: &Arn) -> bool {
+        let other = other.as_arn_ref().as_str();
+        other.len() == self.inner.scope.prefix.len() + self.inner.resource.len()
+            && other.starts_with(&*self.inner.scope.prefix)
+            && other.ends_with(&*self.inner.resource)
+    }
+}
+
+impl PartialEq<InternedArn> for Arn {
+    fn eq(&self, other: &InternedArn) -> bool {
+        other == self
+    }
+}
+
+impl From<&InternedArn> for Arn {
+    fn from(arn: &InternedArn) -> Self {
+        arn.to_arn()
+    }
+}
+
+impl From<InternedArn> for Arn {
+    fn from(arn: InternedArn) -> Self {
+        arn.to_arn()
+    }
+}
+
+/// An interned ARN keyed by its resource, for lookups within a scope.
+struct ByResource(InternedArn);
+
+impl Borrow<str> for ByResource {
+    fn borrow(&self) -> &str {
+        self.0.resource()
+    }
+}
+
+impl PartialEq for ByResource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.resource() == other.0.resource()
+    }
+}
+
+impl Eq for ByResource {}
+
+impl Hash for ByResource {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.resource().hash(state)
+    }
+}
+
+/// Deduplicates ARNs to reduce the memory needed to hold large numbers of them.
+///
+/// Each distinct `arn:partition:service:region:account-id:` prefix is stored once, and each distinct ARN is stored
+/// once. The interner holds a reference to every ARN it has handed out until [ArnInterner::purge] is called.
+///
+/// ```
+/// # use scratchstack_arn::{Arn, ArnInterner};
+/// # use std::str::FromStr;
+/// let mut interner = ArnInterner::new();
+/// let a = interner.intern_str("arn:aws:s3:::bucket/key").unwrap();
+/// let b = interner.intern(&Arn::from_str("arn:aws:s3:::bucket/key").unwrap());
+/// assert!(a.ptr_eq(&b));
+/// assert_eq!(Arn::from(a).resource(), "bucket/key");
+/// ```
+pub struct ArnInterner {
+    id: u64,
+    scopes: HashMap<Box<str>, (Arc<Scope>, HashSet<ByResource>)>,
+    len: usize,
+}
+
+impl ArnInterner {
+    /// Create a new, empty interner.
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_INTERNER_ID.fetch_add(1, AtomicOrdering::Relaxed),
+            scopes: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// The number of distinct ARNs held by the interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Indicates whether the interner is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Intern the specified ARN, which may be an [Arn] or an [ArnRef].
+    pub fn intern<'a, A: Into<ArnRef<'a>>>(&mut self, arn: A) -> InternedArn {
+        let arn = arn.into();
+        let resource = arn.resource();
+        let s = arn.as_str();
+        let prefix = &s[..s.len() - resource.len()];
+
+        if !self.scopes.contains_key(prefix) {
+            let service_start = 4 + arn.partition().len() + 1;
+            let region_start = service_start + arn.service().len() + 1;
+            let account_id_start = region_start + arn.region().len() + 1;
+            let scope = Scope {
+                prefix: prefix.into(),
+                service_start,
+                region_start,
+                account_id_start,
+            };
+            self.scopes.insert(prefix.into(), (Arc::new(scope), HashSet::new()));
+        }
+
+        let (scope, resources) = self.scopes.get_mut(prefix).expect("scope was just inserted");
+        if let Some(existing) = resources.get(resource) {
+            return existing.0.clone();
+        }
+
+        let interned = InternedArn {
+            inner: Arc::new(Inner {
+                scope: scope.clone(),
+                resource: resource.into(),
+                interner_id: self.id,
+            }),
+        };
+        resources.insert(ByResource(interned.clone()));
+        self.len += 1;
+        interned
+    }
+
+    /// Parse and intern the specified ARN string.
+    ///
+    /// # Errors
+    ///
+    /// The errors are the same as for [Arn::from_str](std::str::FromStr::from_str).
+    pub fn intern_str(&mut self, s: &str) -> Result<InternedArn, ArnError> {
+        Ok(self.intern(ArnRef::parse(s)?))
+    }
+
+    /// Release ARNs that are no longer referenced outside of the interner.
+    ///
+    /// ARNs that are still referenced elsewhere are kept, so interning them again returns the same copy.
+    pub fn purge(&mut self) {
+        let mut len = 0;
+        self.scopes.retain(|_, (_, resources)| {
+            resources.retain(|r| Arc::strong_count(&r.0.inner) > 1);
+            len += resources.len();
+            !resources.is_empty()
+        });
+        self.len = len;
+    }
+}
+
+impl Default for ArnInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for ArnInterner {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("ArnInterner").field("scopes", &self.scopes.len()).field("len", &self.len).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::ArnInterner,
+        crate::Arn,
+        pretty_assertions::assert_eq,
+        std::{
+            collections::{hash_map::DefaultHasher, HashSet},
+            hash::{Hash, Hasher},
+            str::FromStr,
+            sync::Arc,
+        },
+    };
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn check_intern() {
+        let mut interner = ArnInterner::default();
+        assert!(interner.is_empty());
+
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        let a = interner.intern(&arn);
+        let b = interner.intern_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        let c = interner.intern_str("arn:aws:ec2:us-east-1:123456789012:instance/i-2").unwrap();
+        let d = interner.intern_str("arn:aws:s3:::bucket").unwrap();
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.scopes.len(), 2);
+
+        assert!(a.ptr_eq(&b));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(Arc::ptr_eq(&a.inner.scope, &c.inner.scope));
+        assert_eq!(hash(&a), hash(&b));
+
+        assert_eq!(a.partition(), "aws");
+        assert_eq!(a.service(), "ec2");
+        assert_eq!(a.region(), "us-east-1");
+        assert_eq!(a.account_id(), "123456789012");
+        assert_eq!(a.resource(), "instance/i-1");
+        assert_eq!(d.region(), "");
+        assert_eq!(d.account_id(), "");
+        assert_eq!(a.to_string(), "arn:aws:ec2:us-east-1:123456789012:instance/i-1");
+        assert_eq!(Arn::from(&a), arn);
+        assert_eq!(Arn::from(d.clone()), Arn::from_str("arn:aws:s3:::bucket").unwrap());
+        assert!(a == arn);
+        assert!(arn == a);
+        assert!(arn != c);
+        assert!(a != Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-10").unwrap());
+
+        assert!(a < c);
+        assert!(c < d);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+        let mut sorted = vec![d.clone(), c.clone(), a.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![a.clone(), c.clone(), d.clone()]);
+
+        let set: HashSet<_> = [a.clone(), b.clone(), c.clone()].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        assert_eq!(
+            interner.intern_str("arn:aws:ec2").unwrap_err(),
+            crate::ArnError::InvalidArn("arn:aws:ec2".to_string())
+        );
+
+        let _ = format!("{a:?} {interner:?}");
+    }
+
+    #[test]
+    fn check_across_interners() {
+        let mut i1 = ArnInterner::new();
+        let mut i2 = ArnInterner::new();
+
+        let a = i1.intern_str("arn:aws:s3:::bucket").unwrap();
+        let b = i2.intern_str("arn:aws:s3:::bucket").unwrap();
+        let c = i2.intern_str("arn:aws:s3:::bucket2").unwrap();
+        assert!(!a.ptr_eq(&b));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn check_purge() {
+        let mut interner = ArnInterner::new();
+        let kept = interner.intern_str("arn:aws:s3:::kept").unwrap();
+        interner.intern_str("arn:aws:s3:::dropped").unwrap();
+        interner.intern_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        assert_eq!(interner.len(), 3);
+
+        interner.purge();
+        assert_eq!(interner.len(), 1);
+        assert_eq!(interner.scopes.len(), 1);
+        assert!(interner.intern_str("arn:aws:s3:::kept").unwrap().ptr_eq(&kept));
+        assert_eq!(interner.len(), 1);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,657 @@
+use {
+    crate::{Arn, ArnError, ScratchstackRelaxed, ValidationProfile},
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// The prefix of multi-region key ids.
+const MULTI_REGION_PREFIX: &str = "mrk-";
+
+/// The prefix of aliases for AWS managed keys.
+const AWS_MANAGED_ALIAS_PREFIX: &str = "aws/";
+
+/// A KMS key, with an ARN in the form `arn:partition:kms:region:account-id:key/key-id`.
+///
+/// The key id is either a UUID for a single-region key or an `mrk-` id for a multi-region key. The primary key and
+/// replicas of a multi-region key share the same key id in different regions; see [KmsKey::replica] and
+/// [KmsKey::is_replica_of].
+///
+/// KmsKey structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KmsKey {
+    /// The partition the key exists in.
+    partition: String,
+
+    /// The region the key exists in.
+    region: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The id of the key.
+    key_id: String,
+}
+
+impl KmsKey {
+    /// Create a [KmsKey] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition the key exists in. This must meet the rules specified in
+    ///   [utils::validate_partition](crate::utils::validate_partition) or an [ArnError::InvalidPartition] error will
+    ///   be returned.
+    /// * `region`: The region the key exists in. This must meet the rules specified in
+    ///   [utils::validate_region](crate::utils::validate_region) or an [ArnError::InvalidRegion] error will be
+    ///   returned.
+    /// * `account_id`: The account id. This must meet the rules specified in
+    ///   [utils::validate_account_id](crate::utils::validate_account_id) or an [ArnError::InvalidAccountId] error
+    ///   will be returned.
+    /// * `key_id`: The id of the key. This must be a UUID in lowercase hexadecimal for a single-region key (e.g.
+    ///   `1234abcd-12ab-34cd-56ef-1234567890ab`) or `mrk-` followed by 32 lowercase hexadecimal digits for a
+    ///   multi-region key (e.g. `mrk-1234abcd12ab34cd56ef1234567890ab`). Otherwise, an [ArnError::InvalidResource]
+    ///   error will be returned.
+    pub fn new(partition: &str, region: &str, account_id: &str, key_id: &str) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, region, account_id, key_id, &ScratchstackRelaxed)
+    }
+
+    /// Create a [KmsKey] object, validating the partition, region, and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [KmsKey::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        key_id: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_region(region)?;
+        profile.validate_account_id(account_id)?;
+        validate_key_id(key_id)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            key_id: key_id.into(),
+        })
+    }
+
+    /// The partition of the key.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the key.
+    #[inline]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The account ID of the key.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The id of the key.
+    #[inline]
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Indicates whether this is a multi-region key.
+    #[inline]
+    pub fn is_multi_region(&self) -> bool {
+        self.key_id.starts_with(MULTI_REGION_PREFIX)
+    }
+
+    /// Create the ARN of the replica of this multi-region key in the specified region. If `region` is the region of
+    /// this key, a copy of this key is returned.
+    ///
+    /// # Errors
+    ///
+    /// If this is not a multi-region key, [ArnError::InvalidResource] is returned. If the region is invalid,
+    /// [ArnError::InvalidRegion] is returned.
+    pub fn replica(&self, region: &str) -> Result<Self, ArnError> {
+        if !self.is_multi_region() {
+            return Err(ArnError::InvalidResource(self.key_id.clone()));
+        }
+
+        Self::new(&self.partition, region, &self.account_id, &self.key_id)
+    }
+
+    /// Indicates whether `other` is a related multi-region key in another region: either the primary key of this
+    /// replica, or a replica of this key.
+    pub fn is_replica_of(&self, other: &Self) -> bool {
+        self.is_multi_region()
+            && self.partition == other.partition
+            && self.region != other.region
+            && self.account_id == other.account_id
+            && self.key_id == other.key_id
+    }
+}
+
+impl From<&KmsKey> for Arn {
+    fn from(key: &KmsKey) -> Arn {
+        // Safety: All components were validated when the key was created.
+        unsafe {
+            Arn::new_unchecked(&key.partition, "kms", &key.region, &key.account_id, &format!("key/{}", key.key_id))
+        }
+    }
+}
+
+impl FromStr for KmsKey {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning a [KmsKey] if the ARN is a valid key ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for KmsKey {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid KMS key, convert it to a [KmsKey]; otherwise, return an [ArnError] indicating
+    /// what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_kms_arn(arn)?;
+
+        let resource = arn.resource();
+        let Some(key_id) = resource.strip_prefix("key/") else {
+            return Err(ArnError::InvalidResource(resource.to_string()));
+        };
+
+        Self::new(arn.partition(), arn.region(), arn.account_id(), key_id)
+    }
+}
+
+impl Display for KmsKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:kms:{}:{}:key/{}", self.partition, self.region, self.account_id, self.key_id)
+    }
+}
+
+/// A KMS alias, with an ARN in the form `arn:partition:kms:region:account-id:alias/alias-name`.
+///
+/// The alias name is stored without the `alias/` prefix.
+///
+/// KmsAlias structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KmsAlias {
+    /// The partition the alias exists in.
+    partition: String,
+
+    /// The region the alias exists in.
+    region: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The name of the alias, without the `alias/` prefix.
+    alias_name: String,
+}
+
+impl KmsAlias {
+    /// Create a [KmsAlias] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`, `region`, `account_id`: As for [KmsKey::new].
+    /// * `alias_name`: The name of the alias, without the `alias/` prefix. This must contain between 1 and 250 ASCII
+    ///   letters, digits, `/`, `_`, or `-` or an [ArnError::InvalidResource] error will be returned.
+    pub fn new(partition: &str, region: &str, account_id: &str, alias_name: &str) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, region, account_id, alias_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [KmsAlias] object, validating the partition, region, and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [KmsAlias::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        alias_name: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_region(region)?;
+        profile.validate_account_id(account_id)?;
+        validate_alias_name(alias_name)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            alias_name: alias_name.into(),
+        })
+    }
+
+    /// The partition of the alias.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the alias.
+    #[inline]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The account ID of the alias.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The name of the alias, without the `alias/` prefix.
+    #[inline]
+    pub fn alias_name(&self) -> &str {
+        &self.alias_name
+    }
+
+    /// Indicates whether this is the alias of an AWS managed key, such as `alias/aws/s3`.
+    #[inline]
+    pub fn is_aws_managed(&self) -> bool {
+        self.alias_name.starts_with(AWS_MANAGED_ALIAS_PREFIX)
+    }
+}
+
+impl From<&KmsAlias> for Arn {
+    fn from(alias: &KmsAlias) -> Arn {
+        let resource = format!("alias/{}", alias.alias_name);
+
+        // Safety: All components were validated when the alias was created.
+        unsafe { Arn::new_unchecked(&alias.partition, "kms", &alias.region, &alias.account_id, &resource) }
+    }
+}
+
+impl FromStr for KmsAlias {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning a [KmsAlias] if the ARN is a valid alias ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for KmsAlias {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid KMS alias, convert it to a [KmsAlias]; otherwise, return an [ArnError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_kms_arn(arn)?;
+
+        let resource = arn.resource();
+        let Some(alias_name) = resource.strip_prefix("alias/") else {
+            return Err(ArnError::InvalidResource(resource.to_string()));
+        };
+
+        Self::new(arn.partition(), arn.region(), arn.account_id(), alias_name)
+    }
+}
+
+impl Display for KmsAlias {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:kms:{}:{}:alias/{}", self.partition, self.region, self.account_id, self.alias_name)
+    }
+}
+
+/// A KMS key identifier, as accepted by the KMS APIs: a key id, an alias name, a key ARN, or an alias ARN.
+///
+/// Bare key ids and alias names are relative to a region and account; use [KmsKeyIdentifier::resolve] to turn any
+/// identifier into a full ARN.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum KmsKeyIdentifier {
+    /// A bare key id: `1234abcd-12ab-34cd-56ef-1234567890ab` or `mrk-1234abcd12ab34cd56ef1234567890ab`.
+    KeyId(String),
+
+    /// An alias name, stored without the `alias/` prefix: `alias/ExampleAlias`.
+    AliasName(String),
+
+    /// A key ARN: `arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab`.
+    KeyArn(KmsKey),
+
+    /// An alias ARN: `arn:aws:kms:us-east-1:123456789012:alias/ExampleAlias`.
+    AliasArn(KmsAlias),
+}
+
+impl KmsKeyIdentifier {
+    /// The key id, if this identifies a key directly instead of through an alias.
+    pub fn key_id(&self) -> Option<&str> {
+        match self {
+            Self::KeyId(key_id) => Some(key_id),
+            Self::KeyArn(key) => Some(key.key_id()),
+            Self::AliasName(_) | Self::AliasArn(_) => None,
+        }
+    }
+
+    /// The alias name, without the `alias/` prefix, if this identifies a key through an alias.
+    pub fn alias_name(&self) -> Option<&str> {
+        match self {
+            Self::AliasName(alias_name) => Some(alias_name),
+            Self::AliasArn(alias) => Some(alias.alias_name()),
+            Self::KeyId(_) | Self::KeyArn(_) => None,
+        }
+    }
+
+    /// Indicates whether this identifier is a full ARN.
+    #[inline]
+    pub fn is_arn(&self) -> bool {
+        matches!(self, Self::KeyArn(_) | Self::AliasArn(_))
+    }
+
+    /// Indicates whether this identifies a multi-region key. Aliases always return `false`, since the key they point
+    /// to is unknown.
+    pub fn is_multi_region(&self) -> bool {
+        self.key_id().is_some_and(|key_id| key_id.starts_with(MULTI_REGION_PREFIX))
+    }
+
+    /// Resolve this identifier into a key ARN or alias ARN. Bare key ids and alias names are resolved against the
+    /// specified partition, region, and account id; ARNs are returned unchanged, since they may legitimately refer to
+    /// keys in other accounts.
+    ///
+    /// # Errors
+    ///
+    /// If this is a bare key id or alias name and the partition, region, or account id is invalid, the corresponding
+    /// [ArnError] is returned.
+    pub fn resolve(&self, partition: &str, region: &str, account_id: &str) -> Result<Arn, ArnError> {
+        self.resolve_with_profile(partition, region, account_id, &ScratchstackRelaxed)
+    }
+
+    /// Resolve this identifier into a key ARN or alias ARN, validating the partition, region, and account id
+    /// according to the specified [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [KmsKeyIdentifier::resolve].
+    pub fn resolve_with_profile<P: ValidationProfile + ?Sized>(
+        &self,
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        profile: &P,
+    ) -> Result<Arn, ArnError> {
+        match self {
+            Self::KeyId(key_id) => {
+                Ok(Arn::from(&KmsKey::new_with_profile(partition, region, account_id, key_id, profile)?))
+            }
+            Self::AliasName(alias_name) => {
+                Ok(Arn::from(&KmsAlias::new_with_profile(partition, region, account_id, alias_name, profile)?))
+            }
+            Self::KeyArn(key) => Ok(Arn::from(key)),
+            Self::AliasArn(alias) => Ok(Arn::from(alias)),
+        }
+    }
+}
+
+impl From<KmsKey> for KmsKeyIdentifier {
+    fn from(key: KmsKey) -> Self {
+        Self::KeyArn(key)
+    }
+}
+
+impl From<KmsAlias> for KmsKeyIdentifier {
+    fn from(alias: KmsAlias) -> Self {
+        Self::AliasArn(alias)
+    }
+}
+
+impl FromStr for KmsKeyIdentifier {
+    type Err = ArnError;
+
+    /// Parse a key identifier in any of the four forms accepted by KMS.
+    ///
+    /// # Errors
+    ///
+    /// If the identifier is an ARN, the errors are the same as for [KmsKey::from_str] or [KmsAlias::from_str].
+    /// Otherwise, if the key id or alias name is invalid, [ArnError::InvalidResource] is returned.
+    fn from_str(identifier: &str) -> Result<Self, ArnError> {
+        if identifier.starts_with("arn:") {
+            let arn = Arn::from_str(identifier)?;
+            return if arn.resource().starts_with("alias/") {
+                Ok(Self::AliasArn(KmsAlias::try_from(&arn)?))
+            } else {
+                Ok(Self::KeyArn(KmsKey::try_from(&arn)?))
+            };
+        }
+
+        if let Some(alias_name) = identifier.strip_prefix("alias/") {
+            validate_alias_name(alias_name)?;
+            return Ok(Self::AliasName(alias_name.to_string()));
+        }
+
+        validate_key_id(identifier)?;
+        Ok(Self::KeyId(identifier.to_string()))
+    }
+}
+
+/// Writes the identifier in the form it was parsed from.
+impl Display for KmsKeyIdentifier {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::KeyId(key_id) => f.write_str(key_id),
+            Self::AliasName(alias_name) => write!(f, "alias/{alias_name}"),
+            Self::KeyArn(key) => Display::fmt(key, f),
+            Self::AliasArn(alias) => Display::fmt(alias, f),
+        }
+    }
+}
+
+/// Verify that `arn` is for the KMS service.
+fn check_kms_arn(arn: &Arn) -> Result<(), ArnError> {
+    if arn.service() == "kms" {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidService(arn.service().to_string()))
+    }
+}
+
+/// Verify that a KMS key id is a single-region key id or a multi-region key id.
+///
+/// *   A single-region key id is a UUID in lowercase hexadecimal, e.g. `1234abcd-12ab-34cd-56ef-1234567890ab`.
+/// *   A multi-region key id is `mrk-` followed by 32 lowercase hexadecimal digits, e.g.
+///     `mrk-1234abcd12ab34cd56ef1234567890ab`.
+///
+/// If `key_id` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidResource] error is returned.
+fn validate_key_id(key_id: &str) -> Result<(), ArnError> {
+    let is_hex = |s: &str| s.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+
+    let valid = match key_id.strip_prefix(MULTI_REGION_PREFIX) {
+        Some(hex) => hex.len() == 32 && is_hex(hex),
+        None => key_id.split('-').map(str::len).eq([8, 4, 4, 4, 12]) && key_id.split('-').all(is_hex),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidResource(key_id.to_string()))
+    }
+}
+
+/// Verify that an alias name, without the `alias/` prefix, contains between 1 and 250 ASCII letters, digits, `/`,
+/// `_`, or `-`.
+fn validate_alias_name(alias_name: &str) -> Result<(), ArnError> {
+    if (1..=250).contains(&alias_name.len())
+        && alias_name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'/' || b == b'_' || b == b'-')
+    {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidResource(alias_name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{validate_key_id, KmsAlias, KmsKey, KmsKeyIdentifier},
+        crate::{Arn, ArnError, AwsStrict},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    const KEY_ID: &str = "1234abcd-12ab-34cd-56ef-1234567890ab";
+    const MRK_ID: &str = "mrk-1234abcd12ab34cd56ef1234567890ab";
+
+    #[test]
+    fn check_key() {
+        let arn = format!("arn:aws:kms:us-east-1:123456789012:key/{KEY_ID}");
+        let key = KmsKey::from_str(&arn).unwrap();
+        assert_eq!(key.partition(), "aws");
+        assert_eq!(key.region(), "us-east-1");
+        assert_eq!(key.account_id(), "123456789012");
+        assert_eq!(key.key_id(), KEY_ID);
+        assert!(!key.is_multi_region());
+        assert_eq!(key.to_string(), arn);
+        assert_eq!(Arn::from(&key).to_string(), arn);
+        assert_eq!(KmsKey::new_with_profile("aws", "us-east-1", "123456789012", KEY_ID, &AwsStrict).unwrap(), key);
+
+        assert_eq!(key.replica("us-west-2").unwrap_err(), ArnError::InvalidResource(KEY_ID.to_string()));
+        let other_region = KmsKey::new("aws", "us-west-2", "123456789012", KEY_ID).unwrap();
+        assert!(!key.is_replica_of(&other_region));
+
+        for (arn, err) in [
+            ("arn:aws:kms:us-east-1:123456789012:key/not-a-key", ArnError::InvalidResource("not-a-key".to_string())),
+            ("arn:aws:kms:us-east-1:123456789012:alias/a", ArnError::InvalidResource("alias/a".to_string())),
+            (
+                "arn:aws:kms::123456789012:key/mrk-1234abcd12ab34cd56ef1234567890ab",
+                ArnError::InvalidRegion("".to_string()),
+            ),
+            ("arn:aws:s3:us-east-1:123456789012:key/x", ArnError::InvalidService("s3".to_string())),
+        ] {
+            assert_eq!(KmsKey::from_str(arn).unwrap_err(), err, "{arn}");
+        }
+    }
+
+    #[test]
+    fn check_multi_region_key() {
+        let primary = KmsKey::new("aws", "us-east-1", "123456789012", MRK_ID).unwrap();
+        assert!(primary.is_multi_region());
+
+        let replica = primary.replica("eu-west-1").unwrap();
+        assert_eq!(replica.region(), "eu-west-1");
+        assert_eq!(replica.key_id(), MRK_ID);
+        assert_eq!(replica.to_string(), format!("arn:aws:kms:eu-west-1:123456789012:key/{MRK_ID}"));
+        assert!(replica.is_replica_of(&primary));
+        assert!(primary.is_replica_of(&replica));
+        assert!(!primary.is_replica_of(&primary));
+        assert_eq!(primary.replica("us-east-1").unwrap(), primary);
+
+        let other_account = KmsKey::new("aws", "eu-west-1", "210987654321", MRK_ID).unwrap();
+        assert!(!other_account.is_replica_of(&primary));
+
+        assert_eq!(primary.replica("").unwrap_err(), ArnError::InvalidRegion("".to_string()));
+    }
+
+    #[test]
+    fn check_alias() {
+        let arn = "arn:aws:kms:us-east-1:123456789012:alias/my/app-key_1";
+        let alias = KmsAlias::from_str(arn).unwrap();
+        assert_eq!(alias.partition(), "aws");
+        assert_eq!(alias.region(), "us-east-1");
+        assert_eq!(alias.account_id(), "123456789012");
+        assert_eq!(alias.alias_name(), "my/app-key_1");
+        assert!(!alias.is_aws_managed());
+        assert_eq!(alias.to_string(), arn);
+        assert_eq!(Arn::from(&alias).to_string(), arn);
+
+        assert!(KmsAlias::new("aws", "us-east-1", "123456789012", "aws/s3").unwrap().is_aws_managed());
+        KmsAlias::new("aws", "us-east-1", "123456789012", &"a".repeat(250)).unwrap();
+
+        for name in ["", "my.alias", "my alias", &"a".repeat(251)] {
+            let err = KmsAlias::new("aws", "us-east-1", "123456789012", name).unwrap_err();
+            assert_eq!(err, ArnError::InvalidResource(name.to_string()), "{name}");
+        }
+
+        let err = KmsAlias::from_str(&format!("arn:aws:kms:us-east-1:123456789012:key/{KEY_ID}")).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource(format!("key/{KEY_ID}")));
+    }
+
+    #[test]
+    fn check_identifiers() {
+        let key_arn = format!("arn:aws:kms:us-east-1:123456789012:key/{KEY_ID}");
+        let alias_arn = "arn:aws:kms:us-east-1:123456789012:alias/ExampleAlias";
+
+        for (identifier, expected, resolved) in [
+            (KEY_ID, KmsKeyIdentifier::KeyId(KEY_ID.to_string()), key_arn.as_str()),
+            ("alias/ExampleAlias", KmsKeyIdentifier::AliasName("ExampleAlias".to_string()), alias_arn),
+            (key_arn.as_str(), KmsKey::from_str(&key_arn).unwrap().into(), key_arn.as_str()),
+            (alias_arn, KmsAlias::from_str(alias_arn).unwrap().into(), alias_arn),
+        ] {
+            let parsed = KmsKeyIdentifier::from_str(identifier).unwrap();
+            assert_eq!(parsed, expected);
+            assert_eq!(parsed.to_string(), identifier);
+            assert_eq!(parsed.resolve("aws", "us-east-1", "123456789012").unwrap().to_string(), resolved);
+        }
+
+        // ARNs are not re-homed to the resolution context.
+        let parsed = KmsKeyIdentifier::from_str(&key_arn).unwrap();
+        assert_eq!(parsed.resolve("aws-cn", "cn-north-1", "210987654321").unwrap().to_string(), key_arn);
+        assert!(parsed.is_arn());
+        assert_eq!(parsed.key_id(), Some(KEY_ID));
+        assert_eq!(parsed.alias_name(), None);
+        assert!(!parsed.is_multi_region());
+
+        let parsed = KmsKeyIdentifier::from_str("alias/aws/ebs").unwrap();
+        assert!(!parsed.is_arn());
+        assert_eq!(parsed.key_id(), None);
+        assert_eq!(parsed.alias_name(), Some("aws/ebs"));
+        assert_eq!(
+            parsed.resolve_with_profile("aws", "us-west-2", "123456789012", &AwsStrict).unwrap().to_string(),
+            "arn:aws:kms:us-west-2:123456789012:alias/aws/ebs"
+        );
+
+        let parsed = KmsKeyIdentifier::from_str(MRK_ID).unwrap();
+        assert!(parsed.is_multi_region());
+        let err = parsed.resolve("aws", "", "123456789012").unwrap_err();
+        assert_eq!(err, ArnError::InvalidRegion("".to_string()));
+
+        for (identifier, err) in [
+            ("", ArnError::InvalidResource("".to_string())),
+            (
+                "1234ABCD-12AB-34CD-56EF-1234567890AB",
+                ArnError::InvalidResource("1234ABCD-12AB-34CD-56EF-1234567890AB".to_string()),
+            ),
+            ("alias/", ArnError::InvalidResource("".to_string())),
+            ("alias/bad.name", ArnError::InvalidResource("bad.name".to_string())),
+            ("arn:aws:kms:us-east-1:123456789012:grant/x", ArnError::InvalidResource("grant/x".to_string())),
+            ("arn:aws:sns:us-east-1:123456789012:alias/x", ArnError::InvalidService("sns".to_string())),
+        ] {
+            assert_eq!(KmsKeyIdentifier::from_str(identifier).unwrap_err(), err, "{identifier}");
+        }
+    }
+
+    #[test]
+    fn check_key_ids() {
+        for key_id in ["1234abcd-12ab-34cd-56ef-1234567890ab", "mrk-1234abcd12ab34cd56ef1234567890ab"] {
+            assert!(validate_key_id(key_id).is_ok(), "{key_id}");
+        }
+
+        for key_id in [
+            "",
+            "1234ABCD-12AB-34CD-56EF-1234567890AB",
+            "1234abcd-12ab-34cd-56ef-1234567890a",
+            "1234abcd12ab34cd56ef1234567890ab",
+            "1234abcd-12ab-34cd-56ef-1234567890ag",
+            "1234abcd-12ab-34cd-56ef1-234567890ab",
+            "mrk-1234abcd-12ab-34cd-56ef-1234567890ab",
+            "mrk-1234abcd12ab34cd56ef1234567890a",
+            "MRK-1234abcd12ab34cd56ef1234567890ab",
+        ] {
+            assert!(validate_key_id(key_id).is_err(), "{key_id}");
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,668 @@
+use {
+    crate::{Arn, ArnError, ScratchstackRelaxed, ValidationProfile},
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// The qualifier naming the unpublished version of a function.
+const LATEST: &str = "$LATEST";
+
+/// The qualifier of a Lambda function ARN, selecting a version of the function.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LambdaQualifier {
+    /// No qualifier: `function:name`. Invoking an unqualified ARN invokes `$LATEST`, but permissions granted on an
+    /// unqualified ARN do not apply to qualified ARNs.
+    Unqualified,
+
+    /// The unpublished version of the function: `function:name:$LATEST`.
+    Latest,
+
+    /// A published version of the function: `function:name:42`.
+    Version(u64),
+
+    /// An alias pointing to a version of the function: `function:name:prod`.
+    Alias(String),
+}
+
+impl LambdaQualifier {
+    /// Indicates whether this is a qualifier other than [LambdaQualifier::Unqualified].
+    #[inline]
+    pub fn is_qualified(&self) -> bool {
+        !matches!(self, Self::Unqualified)
+    }
+}
+
+impl FromStr for LambdaQualifier {
+    type Err = ArnError;
+
+    /// Parse a qualifier as it appears after the function name: `$LATEST`, a version number, or an alias.
+    ///
+    /// Aliases must contain between 1 and 128 ASCII letters, digits, `-`, or `_`, and must not be all digits. An
+    /// empty string is not a valid qualifier; use [LambdaQualifier::Unqualified] directly.
+    ///
+    /// # Errors
+    ///
+    /// If the qualifier is invalid, [ArnError::InvalidResource] is returned.
+    fn from_str(qualifier: &str) -> Result<Self, ArnError> {
+        if qualifier == LATEST {
+            return Ok(Self::Latest);
+        }
+
+        if !qualifier.is_empty() && qualifier.bytes().all(|b| b.is_ascii_digit()) {
+            return match qualifier.parse() {
+                Ok(version) if !qualifier.starts_with('0') => Ok(Self::Version(version)),
+                _ => Err(ArnError::InvalidResource(qualifier.to_string())),
+            };
+        }
+
+        if is_valid_name(qualifier, 128) {
+            Ok(Self::Alias(qualifier.to_string()))
+        } else {
+            Err(ArnError::InvalidResource(qualifier.to_string()))
+        }
+    }
+}
+
+/// Writes the qualifier as it appears after the function name. [LambdaQualifier::Unqualified] is written as an empty
+/// string.
+impl Display for LambdaQualifier {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Unqualified => Ok(()),
+            Self::Latest => f.write_str(LATEST),
+            Self::Version(version) => write!(f, "{version}"),
+            Self::Alias(alias) => f.write_str(alias),
+        }
+    }
+}
+
+/// A Lambda function, with an ARN in the form `arn:partition:lambda:region:account-id:function:name[:qualifier]`.
+///
+/// The derived equality includes the qualifier, so `function:f` and `function:f:1` are not equal. Use
+/// [LambdaFunction::same_function] to compare functions while ignoring the qualifier.
+///
+/// LambdaFunction structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LambdaFunction {
+    /// The partition the function exists in.
+    partition: String,
+
+    /// The region the function exists in.
+    region: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The name of the function.
+    function_name: String,
+
+    /// The qualifier selecting a version of the function.
+    qualifier: LambdaQualifier,
+}
+
+impl LambdaFunction {
+    /// Create a [LambdaFunction] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition the function exists in. This must meet the rules specified in
+    ///   [utils::validate_partition](crate::utils::validate_partition) or an [ArnError::InvalidPartition] error will
+    ///   be returned.
+    /// * `region`: The region the function exists in. This must meet the rules specified in
+    ///   [utils::validate_region](crate::utils::validate_region) or an [ArnError::InvalidRegion] error will be
+    ///   returned.
+    /// * `account_id`: The account id. This must meet the rules specified in
+    ///   [utils::validate_account_id](crate::utils::validate_account_id) or an [ArnError::InvalidAccountId] error
+    ///   will be returned.
+    /// * `function_name`: The name of the function. This must contain between 1 and 64 ASCII letters, digits, `-`, or
+    ///   `_` or an [ArnError::InvalidResource] error will be returned.
+    /// * `qualifier`: The qualifier. Versions must not be 0, and aliases must meet the rules specified in
+    ///   [LambdaQualifier::from_str], or an [ArnError::InvalidResource] error will be returned.
+    pub fn new(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        function_name: &str,
+        qualifier: LambdaQualifier,
+    ) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, region, account_id, function_name, qualifier, &ScratchstackRelaxed)
+    }
+
+    /// Create a [LambdaFunction] object, validating the partition, region, and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [LambdaFunction::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        function_name: &str,
+        qualifier: LambdaQualifier,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_region(region)?;
+        profile.validate_account_id(account_id)?;
+
+        if !is_valid_name(function_name, 64) {
+            return Err(ArnError::InvalidResource(function_name.to_string()));
+        }
+
+        validate_qualifier(&qualifier)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            function_name: function_name.into(),
+            qualifier,
+        })
+    }
+
+    /// The partition of the function.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the function.
+    #[inline]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The account ID of the function.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The name of the function.
+    #[inline]
+    pub fn function_name(&self) -> &str {
+        &self.function_name
+    }
+
+    /// The qualifier of the function.
+    #[inline]
+    pub fn qualifier(&self) -> &LambdaQualifier {
+        &self.qualifier
+    }
+
+    /// Indicates whether the ARN has a qualifier.
+    #[inline]
+    pub fn is_qualified(&self) -> bool {
+        self.qualifier.is_qualified()
+    }
+
+    /// Create a copy of this function ARN with the specified qualifier.
+    ///
+    /// # Errors
+    ///
+    /// If the qualifier is version 0 or an invalid alias, [ArnError::InvalidResource] is returned.
+    pub fn with_qualifier(&self, qualifier: LambdaQualifier) -> Result<Self, ArnError> {
+        validate_qualifier(&qualifier)?;
+
+        Ok(Self {
+            qualifier,
+            ..self.clone()
+        })
+    }
+
+    /// Create a copy of this function ARN without a qualifier.
+    pub fn unqualified(&self) -> Self {
+        Self {
+            qualifier: LambdaQualifier::Unqualified,
+            ..self.clone()
+        }
+    }
+
+    /// Indicates whether `other` refers to the same function, ignoring the qualifier.
+    pub fn same_function(&self, other: &Self) -> bool {
+        self.partition == other.partition
+            && self.region == other.region
+            && self.account_id == other.account_id
+            && self.function_name == other.function_name
+    }
+}
+
+impl From<&LambdaFunction> for Arn {
+    fn from(function: &LambdaFunction) -> Arn {
+        let resource = match function.qualifier {
+            LambdaQualifier::Unqualified => format!("function:{}", function.function_name),
+            ref qualifier => format!("function:{}:{qualifier}", function.function_name),
+        };
+
+        // Safety: All components were validated when the function was created.
+        unsafe { Arn::new_unchecked(&function.partition, "lambda", &function.region, &function.account_id, &resource) }
+    }
+}
+
+impl FromStr for LambdaFunction {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning a [LambdaFunction] if the ARN is a valid function ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for LambdaFunction {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid Lambda function, convert it to a [LambdaFunction]; otherwise, return an
+    /// [ArnError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_lambda_arn(arn)?;
+
+        let resource = arn.resource();
+        let Some(name_and_qualifier) = resource.strip_prefix("function:") else {
+            return Err(ArnError::InvalidResource(resource.to_string()));
+        };
+
+        let (function_name, qualifier) = match name_and_qualifier.split_once(':') {
+            Some((function_name, qualifier)) => (function_name, LambdaQualifier::from_str(qualifier)?),
+            None => (name_and_qualifier, LambdaQualifier::Unqualified),
+        };
+
+        Self::new(arn.partition(), arn.region(), arn.account_id(), function_name, qualifier)
+    }
+}
+
+impl Display for LambdaFunction {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:lambda:{}:{}:function:{}", self.partition, self.region, self.account_id, self.function_name)?;
+        if self.qualifier.is_qualified() {
+            write!(f, ":{}", self.qualifier)?;
+        }
+        Ok(())
+    }
+}
+
+/// A Lambda layer, with an ARN in the form `arn:partition:lambda:region:account-id:layer:name` or, for a specific
+/// version of the layer, `arn:partition:lambda:region:account-id:layer:name:version`.
+///
+/// LambdaLayer structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LambdaLayer {
+    /// The partition the layer exists in.
+    partition: String,
+
+    /// The region the layer exists in.
+    region: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The name of the layer.
+    layer_name: String,
+
+    /// The version of the layer, if this is a layer version ARN.
+    version: Option<u64>,
+}
+
+impl LambdaLayer {
+    /// Create a [LambdaLayer] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`, `region`, `account_id`: As for [LambdaFunction::new].
+    /// * `layer_name`: The name of the layer. This must contain between 1 and 140 ASCII letters, digits, `-`, or `_`
+    ///   or an [ArnError::InvalidResource] error will be returned.
+    /// * `version`: The version of the layer, or `None` for the layer itself. Versions must not be 0 or an
+    ///   [ArnError::InvalidResource] error will be returned.
+    pub fn new(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        layer_name: &str,
+        version: Option<u64>,
+    ) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, region, account_id, layer_name, version, &ScratchstackRelaxed)
+    }
+
+    /// Create a [LambdaLayer] object, validating the partition, region, and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [LambdaLayer::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        layer_name: &str,
+        version: Option<u64>,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_region(region)?;
+        profile.validate_account_id(account_id)?;
+
+        if !is_valid_name(layer_name, 140) {
+            return Err(ArnError::InvalidResource(layer_name.to_string()));
+        }
+
+        if let Some(version) = version {
+            validate_version(version)?;
+        }
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            layer_name: layer_name.into(),
+            version,
+        })
+    }
+
+    /// The partition of the layer.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the layer.
+    #[inline]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The account ID of the layer.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The name of the layer.
+    #[inline]
+    pub fn layer_name(&self) -> &str {
+        &self.layer_name
+    }
+
+    /// The version of the layer, or `None` if this is the ARN of the layer itself.
+    #[inline]
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// Create a copy of this layer ARN for the specified version, or for the layer itself if `version` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// If the version is 0, [ArnError::InvalidResource] is returned.
+    pub fn with_version(&self, version: Option<u64>) -> Result<Self, ArnError> {
+        if let Some(version) = version {
+            validate_version(version)?;
+        }
+
+        Ok(Self {
+            version,
+            ..self.clone()
+        })
+    }
+
+    /// Indicates whether `other` refers to the same layer, ignoring the version.
+    pub fn same_layer(&self, other: &Self) -> bool {
+        self.partition == other.partition
+            && self.region == other.region
+            && self.account_id == other.account_id
+            && self.layer_name == other.layer_name
+    }
+}
+
+impl From<&LambdaLayer> for Arn {
+    fn from(layer: &LambdaLayer) -> Arn {
+        let resource = match layer.version {
+            None => format!("layer:{}", layer.layer_name),
+            Some(version) => format!("layer:{}:{version}", layer.layer_name),
+        };
+
+        // Safety: All components were validated when the layer was created.
+        unsafe { Arn::new_unchecked(&layer.partition, "lambda", &layer.region, &layer.account_id, &resource) }
+    }
+}
+
+impl FromStr for LambdaLayer {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning a [LambdaLayer] if the ARN is a valid layer or layer version ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for LambdaLayer {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid Lambda layer or layer version, convert it to a [LambdaLayer]; otherwise, return
+    /// an [ArnError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_lambda_arn(arn)?;
+
+        let resource = arn.resource();
+        let Some(name_and_version) = resource.strip_prefix("layer:") else {
+            return Err(ArnError::InvalidResource(resource.to_string()));
+        };
+
+        let (layer_name, version) = match name_and_version.split_once(':') {
+            Some((layer_name, version)) => match LambdaQualifier::from_str(version) {
+                Ok(LambdaQualifier::Version(version)) => (layer_name, Some(version)),
+                _ => return Err(ArnError::InvalidResource(version.to_string())),
+            },
+            None => (name_and_version, None),
+        };
+
+        Self::new(arn.partition(), arn.region(), arn.account_id(), layer_name, version)
+    }
+}
+
+impl Display for LambdaLayer {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:lambda:{}:{}:layer:{}", self.partition, self.region, self.account_id, self.layer_name)?;
+        if let Some(version) = self.version {
+            write!(f, ":{version}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Verify that `arn` is for the Lambda service.
+fn check_lambda_arn(arn: &Arn) -> Result<(), ArnError> {
+    if arn.service() == "lambda" {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidService(arn.service().to_string()))
+    }
+}
+
+/// Indicates whether `name` contains between 1 and `max_len` ASCII letters, digits, `-`, or `_`.
+fn is_valid_name(name: &str, max_len: usize) -> bool {
+    (1..=max_len).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+/// Verify that a qualifier meets the rules of [LambdaQualifier::from_str]: aliases must be valid, and versions start
+/// at 1.
+fn validate_qualifier(qualifier: &LambdaQualifier) -> Result<(), ArnError> {
+    match qualifier {
+        LambdaQualifier::Alias(alias) => match LambdaQualifier::from_str(alias) {
+            Ok(LambdaQualifier::Alias(_)) => Ok(()),
+            _ => Err(ArnError::InvalidResource(alias.clone())),
+        },
+        LambdaQualifier::Version(version) => validate_version(*version),
+        _ => Ok(()),
+    }
+}
+
+/// Verify that a function or layer version is not 0. Published versions are numbered from 1.
+fn validate_version(version: u64) -> Result<(), ArnError> {
+    if version == 0 {
+        Err(ArnError::InvalidResource("0".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{LambdaFunction, LambdaLayer, LambdaQualifier},
+        crate::{Arn, ArnError, AwsStrict},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_qualifiers() {
+        assert_eq!(LambdaQualifier::from_str("$LATEST").unwrap(), LambdaQualifier::Latest);
+        assert_eq!(LambdaQualifier::from_str("42").unwrap(), LambdaQualifier::Version(42));
+        assert_eq!(LambdaQualifier::from_str("prod").unwrap(), LambdaQualifier::Alias("prod".to_string()));
+        assert_eq!(LambdaQualifier::from_str("v2-blue_1").unwrap(), LambdaQualifier::Alias("v2-blue_1".to_string()));
+
+        for qualifier in ["", "$latest", "042", "99999999999999999999", "a:b", "a.b", &"a".repeat(129)] {
+            assert_eq!(
+                LambdaQualifier::from_str(qualifier).unwrap_err(),
+                ArnError::InvalidResource(qualifier.to_string()),
+                "{qualifier}"
+            );
+        }
+
+        assert_eq!(LambdaQualifier::Unqualified.to_string(), "");
+        assert_eq!(LambdaQualifier::Latest.to_string(), "$LATEST");
+        assert_eq!(LambdaQualifier::Version(7).to_string(), "7");
+        assert!(!LambdaQualifier::Unqualified.is_qualified());
+        assert!(LambdaQualifier::Version(7).is_qualified());
+    }
+
+    #[test]
+    fn check_function() {
+        let base = "arn:aws:lambda:us-east-1:123456789012:function:my-function";
+        let unqualified = LambdaFunction::from_str(base).unwrap();
+        assert_eq!(unqualified.partition(), "aws");
+        assert_eq!(unqualified.region(), "us-east-1");
+        assert_eq!(unqualified.account_id(), "123456789012");
+        assert_eq!(unqualified.function_name(), "my-function");
+        assert_eq!(unqualified.qualifier(), &LambdaQualifier::Unqualified);
+        assert!(!unqualified.is_qualified());
+        assert_eq!(unqualified.to_string(), base);
+
+        for (suffix, qualifier) in [
+            (":$LATEST", LambdaQualifier::Latest),
+            (":42", LambdaQualifier::Version(42)),
+            (":prod", LambdaQualifier::Alias("prod".to_string())),
+        ] {
+            let arn = Arn::from_str(&format!("{base}{suffix}")).unwrap();
+            let function = LambdaFunction::try_from(&arn).unwrap();
+            assert_eq!(function.qualifier(), &qualifier);
+            assert!(function.is_qualified());
+            assert_eq!(Arn::from(&function), arn);
+            assert_eq!(function.to_string(), arn.to_string());
+
+            assert_ne!(function, unqualified);
+            assert!(function.same_function(&unqualified));
+            assert_eq!(function.unqualified(), unqualified);
+            assert_eq!(unqualified.with_qualifier(qualifier).unwrap(), function);
+        }
+
+        let other = LambdaFunction::from_str("arn:aws:lambda:us-east-1:123456789012:function:other:1").unwrap();
+        assert!(!other.same_function(&unqualified));
+
+        let err = unqualified.with_qualifier(LambdaQualifier::Alias("123".to_string())).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("123".to_string()));
+        let err = LambdaFunction::new("aws", "us-east-1", "123456789012", "f", LambdaQualifier::Alias("".to_string()))
+            .unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("".to_string()));
+
+        LambdaFunction::new_with_profile("aws", "us-east-1", "123456789012", "f", LambdaQualifier::Latest, &AwsStrict)
+            .unwrap();
+    }
+
+    #[test]
+    fn check_version_zero() {
+        // Construction and parsing agree: version 0 is rejected by both, so every constructed ARN round-trips.
+        assert_eq!(LambdaQualifier::from_str("0").unwrap_err(), ArnError::InvalidResource("0".to_string()));
+        let err = LambdaFunction::new("aws", "us-east-1", "123456789012", "f", LambdaQualifier::Version(0));
+        assert_eq!(err.unwrap_err(), ArnError::InvalidResource("0".to_string()));
+        let err = LambdaLayer::new("aws", "us-east-1", "123456789012", "l", Some(0));
+        assert_eq!(err.unwrap_err(), ArnError::InvalidResource("0".to_string()));
+
+        let function =
+            LambdaFunction::new("aws", "us-east-1", "123456789012", "f", LambdaQualifier::Version(1)).unwrap();
+        assert_eq!(LambdaFunction::try_from(&Arn::from(&function)).unwrap(), function);
+        let err = function.with_qualifier(LambdaQualifier::Version(0)).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("0".to_string()));
+
+        let layer = LambdaLayer::new("aws", "us-east-1", "123456789012", "l", Some(1)).unwrap();
+        assert_eq!(LambdaLayer::try_from(&Arn::from(&layer)).unwrap(), layer);
+        assert_eq!(layer.with_version(Some(0)).unwrap_err(), ArnError::InvalidResource("0".to_string()));
+    }
+
+    #[test]
+    fn check_invalid_functions() {
+        for (arn, err) in [
+            (
+                "arn:aws:lambda:us-east-1:123456789012:function:my.function",
+                ArnError::InvalidResource("my.function".to_string()),
+            ),
+            ("arn:aws:lambda:us-east-1:123456789012:function:", ArnError::InvalidResource("".to_string())),
+            ("arn:aws:lambda:us-east-1:123456789012:function:f:", ArnError::InvalidResource("".to_string())),
+            ("arn:aws:lambda:us-east-1:123456789012:function:f:1:2", ArnError::InvalidResource("1:2".to_string())),
+            ("arn:aws:lambda:us-east-1:123456789012:function/f", ArnError::InvalidResource("function/f".to_string())),
+            ("arn:aws:lambda:us-east-1:123456789012:layer:f:1", ArnError::InvalidResource("layer:f:1".to_string())),
+            ("arn:aws:lambda::123456789012:function:f", ArnError::InvalidRegion("".to_string())),
+            ("arn:aws:lambda:us-east-1::function:f", ArnError::InvalidAccountId("".to_string())),
+            ("arn:aws:states:us-east-1:123456789012:function:f", ArnError::InvalidService("states".to_string())),
+        ] {
+            assert_eq!(LambdaFunction::from_str(arn).unwrap_err(), err, "{arn}");
+        }
+
+        let name = "f".repeat(65);
+        let err = LambdaFunction::new("aws", "us-east-1", "123456789012", &name, LambdaQualifier::Unqualified);
+        assert_eq!(err.unwrap_err(), ArnError::InvalidResource(name));
+    }
+
+    #[test]
+    fn check_layer() {
+        let version = LambdaLayer::from_str("arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3").unwrap();
+        assert_eq!(version.partition(), "aws");
+        assert_eq!(version.region(), "us-east-1");
+        assert_eq!(version.account_id(), "123456789012");
+        assert_eq!(version.layer_name(), "my-layer");
+        assert_eq!(version.version(), Some(3));
+        assert_eq!(Arn::from(&version).to_string(), "arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3");
+
+        let layer = LambdaLayer::from_str("arn:aws:lambda:us-east-1:123456789012:layer:my-layer").unwrap();
+        assert_eq!(layer.version(), None);
+        assert_eq!(layer.to_string(), "arn:aws:lambda:us-east-1:123456789012:layer:my-layer");
+        assert!(layer.same_layer(&version));
+        assert_eq!(layer.with_version(Some(3)).unwrap(), version);
+        assert_eq!(version.with_version(None).unwrap(), layer);
+        assert_eq!(LambdaLayer::new("aws", "us-east-1", "123456789012", "my-layer", Some(3)).unwrap(), version);
+
+        for (arn, err) in [
+            (
+                "arn:aws:lambda:us-east-1:123456789012:layer:my-layer:$LATEST",
+                ArnError::InvalidResource("$LATEST".to_string()),
+            ),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:layer:my-layer:prod",
+                ArnError::InvalidResource("prod".to_string()),
+            ),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:layer:my.layer:1",
+                ArnError::InvalidResource("my.layer".to_string()),
+            ),
+            ("arn:aws:lambda:us-east-1:123456789012:function:f", ArnError::InvalidResource("function:f".to_string())),
+            ("arn:aws:s3:us-east-1:123456789012:layer:l:1", ArnError::InvalidService("s3".to_string())),
+        ] {
+            assert_eq!(LambdaLayer::from_str(arn).unwrap_err(), err, "{arn}");
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(clippy::all)]
 #![deny(rustdoc::missing_crate_level_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -7,12 +8,80 @@
 //! ARNs are used to uniquely identify resources in AWS.
 //!
 //! ARNs here represent fully-qualified resources in the form `arn:partition:service:region:account-id:resource`.
-//! No wildcards are allowed in this representation.
+//! No wildcards are allowed in this representation. Resource statements in IAM Aspen policies, which may contain
+//! wildcards, are represented by [ArnPattern] and can be matched against [Arn] objects.
+//!
+//! # Features
+//!
+//! * `std` (default): Enables `PartitionRegistry` and `ArnInterner`. Without this feature, the crate is `no_std` and
+//!   requires only `alloc`.
+//! * `arbitrary`: Implements `Arbitrary` for [Arn] for use in fuzzing, generating valid ARNs.
+//! * `proptest`: Provides the `strategy` module of proptest strategies that generate valid ARNs.
+//! * `rusqlite`: Implements `ToSql` and `FromSql` for [Arn], storing it as text.
 
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod arn;
+mod arn_ref;
+mod canonical;
 mod error;
+#[cfg(feature = "std")]
+mod interner;
+mod kms;
+mod lambda;
+mod map;
+#[cfg(feature = "std")]
+mod partition;
+mod pattern;
+mod profile;
+mod redact;
+mod s3;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+mod translate;
+mod url;
+
+/// Scanning free text, such as logs, stack traces, and JSON documents, for ARNs.
+///
+/// [scan::find] yields each valid ARN along with its byte span, and [scan::replace] rewrites ARNs in place, e.g. to
+/// redact them with a [Redactor].
+pub mod scan;
+
+/// [Proptest](https://docs.rs/proptest) strategies that generate valid ARNs and their components.
+///
+/// This also implements [proptest::arbitrary::Arbitrary] for [Arn], so `any::<Arn>()` may be used.
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+/// Serde adapter that represents an [Arn] as a map of its components.
+///
+/// Use this with `#[serde(with = "scratchstack_arn::structured")]` on an [Arn] field. Serialization writes a map with
+/// the keys `partition`, `service`, `region`, `account_id`, and `resource`; deserialization accepts either this map or
+/// the usual string form.
+pub mod structured;
 
 /// Validation utilities used internally, but may be useful elsewhere.
 pub mod utils;
 
-pub use {arn::Arn, error::ArnError};
+pub use {
+    arn::Arn,
+    arn_ref::ArnRef,
+    canonical::CanonicalArn,
+    error::{ArnComponent, ArnError, ArnErrorReason, ArnParseError},
+    kms::{KmsAlias, KmsKey, KmsKeyIdentifier},
+    lambda::{LambdaFunction, LambdaLayer, LambdaQualifier},
+    map::{ArnMap, ArnSet},
+    pattern::{ArnPattern, ArnSegmentPattern},
+    profile::{AwsStrict, LegacyResources, ScratchstackRelaxed, ValidationProfile},
+    redact::{RedactedArn, Redaction, Redactor},
+    s3::{S3AccessPoint, S3Bucket, S3Object},
+    translate::{PartitionTranslation, TranslationError},
+};
+
+#[cfg(feature = "std")]
+pub use {
+    interner::{ArnInterner, InternedArn},
+    partition::{Partition, PartitionLoadError, PartitionRegistry},
+};
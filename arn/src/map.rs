@@ -0,0 +1,557 @@
+use {
+    crate::{Arn, ArnPattern, ArnSegmentPattern},
+    alloc::{
+        collections::BTreeMap,
+        string::{String, ToString},
+    },
+    core::{
+        borrow::Borrow,
+        cmp::Ordering,
+        fmt::{Debug, Formatter, Result as FmtResult},
+        ops::{Bound, RangeBounds},
+    },
+};
+
+/// An ARN keyed by its resource. Within a single partition, service, region, and account, this orders ARNs the same
+/// way as [Arn]'s [Ord] implementation, and allows lookups and range queries by resource.
+#[derive(Clone)]
+struct ResourceKey(Arn);
+
+impl Borrow<str> for ResourceKey {
+    fn borrow(&self) -> &str {
+        self.0.resource()
+    }
+}
+
+impl PartialEq for ResourceKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.resource() == other.0.resource()
+    }
+}
+
+impl Eq for ResourceKey {}
+
+impl PartialOrd for ResourceKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResourceKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.resource().cmp(other.0.resource())
+    }
+}
+
+type Resources<V> = BTreeMap<ResourceKey, V>;
+type Accounts<V> = BTreeMap<String, Resources<V>>;
+type Regions<V> = BTreeMap<String, Accounts<V>>;
+type Services<V> = BTreeMap<String, Regions<V>>;
+
+/// Return the entry for `key` in `map`, inserting an empty value if it is not present.
+///
+/// Unlike [BTreeMap::entry], this only allocates a key if the entry is not present.
+fn level<'m, T: Default>(map: &'m mut BTreeMap<String, T>, key: &str) -> &'m mut T {
+    if !map.contains_key(key) {
+        map.insert(key.to_string(), T::default());
+    }
+
+    map.get_mut(key).expect("entry was just inserted")
+}
+
+/// Return the entries of `map` whose keys match `segment`, using the literal prefix of the segment to avoid scanning
+/// the entire map.
+fn select<'a, K, T>(
+    map: &'a BTreeMap<K, T>,
+    segment: &'a ArnSegmentPattern,
+) -> impl Iterator<Item = (&'a K, &'a T)> + 'a
+where
+    K: Borrow<str> + Ord,
+{
+    let (prefix, exact) = segment.literal_prefix();
+    map.range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+        .take_while(move |(key, _)| {
+            let key: &str = (*key).borrow();
+            if exact {
+                key == prefix
+            } else {
+                key.starts_with(prefix)
+            }
+        })
+        .filter(move |(key, _)| segment.matches((*key).borrow()))
+}
+
+/// A map from [Arn] to values, indexed by partition, service, region, and account id, then by resource.
+///
+/// Iteration is in [Arn]'s [Ord] order. Queries for ARNs matching an [ArnPattern], ARNs whose resource starts with a
+/// prefix, or ARNs whose resource lies in a range only visit the components and resources that can match rather than
+/// scanning the entire map.
+///
+/// ```
+/// # use scratchstack_arn::{Arn, ArnMap, ArnPattern};
+/// # use std::str::FromStr;
+/// let mut map = ArnMap::new();
+/// map.insert(Arn::from_str("arn:aws:s3:::bucket/photos/cat.jpg").unwrap(), 1);
+/// map.insert(Arn::from_str("arn:aws:s3:::bucket/photos/dog.jpg").unwrap(), 2);
+/// map.insert(Arn::from_str("arn:aws:s3:::bucket/docs/readme.txt").unwrap(), 3);
+///
+/// let photos: Vec<i32> = map.prefix("aws", "s3", "", "", "bucket/photos/").map(|(_, v)| *v).collect();
+/// assert_eq!(photos, vec![1, 2]);
+///
+/// let pattern = ArnPattern::from_str("arn:aws:s3:::bucket/*.txt").unwrap();
+/// assert_eq!(map.matching(&pattern).count(), 1);
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct ArnMap<V> {
+    partitions: BTreeMap<String, Services<V>>,
+    len: usize,
+}
+
+impl<V> ArnMap<V> {
+    /// Create a new, empty map.
+    pub fn new() -> Self {
+        Self {
+            partitions: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// The number of ARNs in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Indicates whether the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all ARNs from the map.
+    pub fn clear(&mut self) {
+        self.partitions.clear();
+        self.len = 0;
+    }
+
+    /// Insert a value for the specified ARN, returning the previous value, if any.
+    pub fn insert(&mut self, arn: Arn, value: V) -> Option<V> {
+        let services = level(&mut self.partitions, arn.partition());
+        let regions = level(services, arn.service());
+        let accounts = level(regions, arn.region());
+        let resources = level(accounts, arn.account_id());
+        let result = resources.insert(ResourceKey(arn), value);
+        if result.is_none() {
+            self.len += 1;
+        }
+        result
+    }
+
+    /// Return the ARNs and values in the scope of the specified partition, service, region, and account id.
+    fn resources(&self, partition: &str, service: &str, region: &str, account_id: &str) -> Option<&Resources<V>> {
+        self.partitions.get(partition)?.get(service)?.get(region)?.get(account_id)
+    }
+
+    /// Return the value for the specified ARN, if present.
+    pub fn get(&self, arn: &Arn) -> Option<&V> {
+        self.resources(arn.partition(), arn.service(), arn.region(), arn.account_id())?.get(arn.resource())
+    }
+
+    /// Return a mutable reference to the value for the specified ARN, if present.
+    pub fn get_mut(&mut self, arn: &Arn) -> Option<&mut V> {
+        self.partitions
+            .get_mut(arn.partition())?
+            .get_mut(arn.service())?
+            .get_mut(arn.region())?
+            .get_mut(arn.account_id())?
+            .get_mut(arn.resource())
+    }
+
+    /// Indicates whether the map contains the specified ARN.
+    pub fn contains_key(&self, arn: &Arn) -> bool {
+        self.get(arn).is_some()
+    }
+
+    /// Remove the specified ARN from the map, returning its value, if present.
+    pub fn remove(&mut self, arn: &Arn) -> Option<V> {
+        let services = self.partitions.get_mut(arn.partition())?;
+        let regions = services.get_mut(arn.service())?;
+        let accounts = regions.get_mut(arn.region())?;
+        let resources = accounts.get_mut(arn.account_id())?;
+        let result = resources.remove(arn.resource())?;
+        self.len -= 1;
+
+        // Prune empty levels so queries do not visit them.
+        if resources.is_empty() {
+            accounts.remove(arn.account_id());
+            if accounts.is_empty() {
+                regions.remove(arn.region());
+                if regions.is_empty() {
+                    services.remove(arn.service());
+                    if services.is_empty() {
+                        self.partitions.remove(arn.partition());
+                    }
+                }
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Iterate over the ARNs and values in the map in [Arn] order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Arn, &V)> {
+        self.partitions
+            .values()
+            .flat_map(|services| services.values())
+            .flat_map(|regions| regions.values())
+            .flat_map(|accounts| accounts.values())
+            .flat_map(|resources| resources.iter())
+            .map(|(key, value)| (&key.0, value))
+    }
+
+    /// Iterate over the ARNs in the map in [Arn] order.
+    pub fn keys(&self) -> impl Iterator<Item = &Arn> {
+        self.iter().map(|(arn, _)| arn)
+    }
+
+    /// Iterate over the values in the map in [Arn] order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs and values matching the specified pattern.
+    ///
+    /// Components with an exact pattern are looked up directly; components with a wildcard pattern only visit
+    /// entries starting with the literal text before the first wildcard. For example, `arn:aws:s3:::bucket/logs/*`
+    /// visits only resources starting with `bucket/logs/`.
+    pub fn matching<'a>(&'a self, pattern: &'a ArnPattern) -> impl Iterator<Item = (&'a Arn, &'a V)> + 'a {
+        select(&self.partitions, pattern.partition())
+            .flat_map(move |(_, services)| select(services, pattern.service()))
+            .flat_map(move |(_, regions)| select(regions, pattern.region()))
+            .flat_map(move |(_, accounts)| select(accounts, pattern.account_id()))
+            .flat_map(move |(_, resources)| select(resources, pattern.resource()))
+            .map(|(key, value)| (&key.0, value))
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs and values in the specified partition, service, region, and account id
+    /// whose resource starts with `resource_prefix`.
+    pub fn prefix<'a>(
+        &'a self,
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource_prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a Arn, &'a V)> + 'a {
+        self.range(partition, service, region, account_id, resource_prefix..)
+            .take_while(move |(arn, _)| arn.resource().starts_with(resource_prefix))
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs and values in the specified partition, service, region, and account id
+    /// whose resource lies within `resources`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [BTreeMap::range]: if the start of the range is greater than the end, or if
+    /// the start and end are equal and both excluded.
+    pub fn range<'a, R: RangeBounds<&'a str>>(
+        &'a self,
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resources: R,
+    ) -> impl Iterator<Item = (&'a Arn, &'a V)> + 'a {
+        let bounds: (Bound<&str>, Bound<&str>) = (resources.start_bound().cloned(), resources.end_bound().cloned());
+        self.resources(partition, service, region, account_id)
+            .into_iter()
+            .flat_map(move |resources| resources.range::<str, _>(bounds))
+            .map(|(key, value)| (&key.0, value))
+    }
+}
+
+impl<V> Default for ArnMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Debug> Debug for ArnMap<V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Extend<(Arn, V)> for ArnMap<V> {
+    fn extend<I: IntoIterator<Item = (Arn, V)>>(&mut self, iter: I) {
+        for (arn, value) in iter {
+            self.insert(arn, value);
+        }
+    }
+}
+
+impl<V> FromIterator<(Arn, V)> for ArnMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Arn, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// A set of [Arn]s, indexed by partition, service, region, and account id, then by resource.
+///
+/// This supports the same queries as [ArnMap].
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct ArnSet {
+    map: ArnMap<()>,
+}
+
+impl ArnSet {
+    /// Create a new, empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of ARNs in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Indicates whether the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Remove all ARNs from the set.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Add an ARN to the set, returning `true` if it was not already present.
+    pub fn insert(&mut self, arn: Arn) -> bool {
+        self.map.insert(arn, ()).is_none()
+    }
+
+    /// Indicates whether the set contains the specified ARN.
+    pub fn contains(&self, arn: &Arn) -> bool {
+        self.map.contains_key(arn)
+    }
+
+    /// Remove an ARN from the set, returning `true` if it was present.
+    pub fn remove(&mut self, arn: &Arn) -> bool {
+        self.map.remove(arn).is_some()
+    }
+
+    /// Iterate over the ARNs in the set in [Arn] order.
+    pub fn iter(&self) -> impl Iterator<Item = &Arn> {
+        self.map.keys()
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs matching the specified pattern. See [ArnMap::matching].
+    pub fn matching<'a>(&'a self, pattern: &'a ArnPattern) -> impl Iterator<Item = &'a Arn> + 'a {
+        self.map.matching(pattern).map(|(arn, _)| arn)
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs in the specified partition, service, region, and account id whose
+    /// resource starts with `resource_prefix`.
+    pub fn prefix<'a>(
+        &'a self,
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resource_prefix: &'a str,
+    ) -> impl Iterator<Item = &'a Arn> + 'a {
+        self.map.prefix(partition, service, region, account_id, resource_prefix).map(|(arn, _)| arn)
+    }
+
+    /// Iterate, in [Arn] order, over the ARNs in the specified partition, service, region, and account id whose
+    /// resource lies within `resources`. See [ArnMap::range].
+    pub fn range<'a, R: RangeBounds<&'a str>>(
+        &'a self,
+        partition: &str,
+        service: &str,
+        region: &str,
+        account_id: &str,
+        resources: R,
+    ) -> impl Iterator<Item = &'a Arn> + 'a {
+        self.map.range(partition, service, region, account_id, resources).map(|(arn, _)| arn)
+    }
+}
+
+impl Debug for ArnSet {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<Arn> for ArnSet {
+    fn extend<I: IntoIterator<Item = Arn>>(&mut self, iter: I) {
+        for arn in iter {
+            self.insert(arn);
+        }
+    }
+}
+
+impl FromIterator<Arn> for ArnSet {
+    fn from_iter<I: IntoIterator<Item = Arn>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::{ArnMap, ArnSet},
+        crate::{Arn, ArnPattern},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    const ARNS: [&str; 10] = [
+        "arn:aws:s3:::bucket/photos/dog.jpg",
+        "arn:aws:ec2:us-east-1:123456789012:instance/i-2",
+        "arn:aws:s3:::bucket/photos/cat.jpg",
+        "arn:aws-cn:ec2:cn-north-1:123456789012:instance/i-1",
+        "arn:aws:ec2:us-west-2:123456789012:instance/i-1",
+        "arn:aws:ec2:us-east-1:123456789012:instance/i-1",
+        "arn:aws:s3:::bucket/docs/readme.txt",
+        "arn:aws:ec2:us-east-1:210987654321:instance/i-1",
+        "arn:aws:s3:::bucket/photos",
+        "arn:aws:iam::123456789012:user/alice",
+    ];
+
+    fn arns() -> Vec<Arn> {
+        ARNS.iter().map(|s| Arn::from_str(s).unwrap()).collect()
+    }
+
+    fn strings<'a>(iter: impl Iterator<Item = &'a Arn>) -> Vec<String> {
+        iter.map(|arn| arn.to_string()).collect()
+    }
+
+    #[test]
+    fn check_map_basics() {
+        let mut map: ArnMap<usize> = arns().into_iter().enumerate().map(|(i, arn)| (arn, i)).collect();
+        assert_eq!(map.len(), 10);
+        assert!(!map.is_empty());
+
+        // Iteration follows Arn's ordering.
+        let mut sorted = arns();
+        sorted.sort();
+        assert_eq!(map.keys().collect::<Vec<_>>(), sorted.iter().collect::<Vec<_>>());
+
+        let arn = Arn::from_str("arn:aws:iam::123456789012:user/alice").unwrap();
+        assert_eq!(map.get(&arn), Some(&9));
+        assert_eq!(map.insert(arn.clone(), 90), Some(9));
+        *map.get_mut(&arn).unwrap() += 1;
+        assert_eq!(map.get(&arn), Some(&91));
+        assert_eq!(map.len(), 10);
+
+        let missing = Arn::from_str("arn:aws:iam::123456789012:user/bob").unwrap();
+        assert!(!map.contains_key(&missing));
+        assert_eq!(map.get_mut(&missing), None);
+        assert_eq!(map.remove(&missing), None);
+        assert_eq!(map.remove(&Arn::from_str("arn:aws:sts::123456789012:user/bob").unwrap()), None);
+
+        // Removing the last ARN in a partition prunes it entirely.
+        let cn = Arn::from_str("arn:aws-cn:ec2:cn-north-1:123456789012:instance/i-1").unwrap();
+        assert_eq!(map.remove(&cn), Some(3));
+        assert_eq!(map.len(), 9);
+        assert!(!map.partitions.contains_key("aws-cn"));
+
+        let rebuilt: ArnMap<usize> = map.iter().map(|(arn, value)| (arn.clone(), *value)).collect();
+        assert_eq!(rebuilt, map);
+        assert_eq!(map.values().sum::<usize>(), 91 + (0..9).sum::<usize>() - 3);
+
+        let _ = format!("{map:?}");
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map, ArnMap::default());
+    }
+
+    #[test]
+    fn check_map_queries() {
+        let map: ArnMap<usize> = arns().into_iter().enumerate().map(|(i, arn)| (arn, i)).collect();
+
+        let photos: Vec<usize> = map.prefix("aws", "s3", "", "", "bucket/photos/").map(|(_, v)| *v).collect();
+        assert_eq!(photos, vec![2, 0]);
+        assert_eq!(map.prefix("aws", "s3", "", "", "bucket/photos").count(), 3);
+        assert_eq!(map.prefix("aws", "s3", "us-east-1", "", "bucket/").count(), 0);
+
+        let range: Vec<usize> =
+            map.range("aws", "s3", "", "", "bucket/d".."bucket/photos/d").map(|(_, v)| *v).collect();
+        assert_eq!(range, vec![6, 8, 2]);
+        assert_eq!(map.range("aws", "s3", "", "", ..).count(), 4);
+
+        let pattern = ArnPattern::from_str("arn:*:*:*:123456789012:*").unwrap();
+        assert_eq!(
+            strings(map.matching(&pattern).map(|(arn, _)| arn)),
+            vec![
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-1",
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-2",
+                "arn:aws:ec2:us-west-2:123456789012:instance/i-1",
+                "arn:aws:iam::123456789012:user/alice",
+                "arn:aws-cn:ec2:cn-north-1:123456789012:instance/i-1",
+            ]
+        );
+
+        let pattern = ArnPattern::from_str("arn:aws:ec2:us-east-1:*:*").unwrap();
+        assert_eq!(map.matching(&pattern).count(), 3);
+
+        let pattern = ArnPattern::from_str("arn:aws:ec2:us-*:*:instance/i-?").unwrap();
+        assert_eq!(map.matching(&pattern).count(), 4);
+
+        let pattern = ArnPattern::from_str("arn:aws:s3:::bucket/*.jpg").unwrap();
+        assert_eq!(
+            strings(map.matching(&pattern).map(|(arn, _)| arn)),
+            vec!["arn:aws:s3:::bucket/photos/cat.jpg", "arn:aws:s3:::bucket/photos/dog.jpg"]
+        );
+
+        let pattern = ArnPattern::from_str("arn:aws:s3:::bucket/photos").unwrap();
+        assert_eq!(map.matching(&pattern).count(), 1);
+
+        let pattern = ArnPattern::from_str("arn:aws:s3:::*").unwrap();
+        assert_eq!(map.matching(&pattern).count(), 4);
+    }
+
+    #[test]
+    fn check_set() {
+        let mut set: ArnSet = arns().into_iter().collect();
+        assert_eq!(set.len(), 10);
+        assert!(!set.is_empty());
+
+        let arn = Arn::from_str("arn:aws:s3:::bucket/photos").unwrap();
+        assert!(set.contains(&arn));
+        assert!(!set.insert(arn.clone()));
+        assert!(set.remove(&arn));
+        assert!(!set.remove(&arn));
+        assert!(set.insert(arn));
+
+        assert_eq!(set.prefix("aws", "s3", "", "", "bucket/photos/").count(), 2);
+        assert_eq!(set.range("aws", "s3", "", "", .."bucket/photos").count(), 1);
+
+        let pattern = ArnPattern::from_str("arn:aws:ec2:*:*:*").unwrap();
+        assert_eq!(
+            strings(set.matching(&pattern)),
+            vec![
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-1",
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-2",
+                "arn:aws:ec2:us-east-1:210987654321:instance/i-1",
+                "arn:aws:ec2:us-west-2:123456789012:instance/i-1",
+            ]
+        );
+
+        let mut sorted = arns();
+        sorted.sort();
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), sorted);
+
+        let _ = format!("{set:?}");
+        set.clear();
+        assert_eq!(set, ArnSet::new());
+    }
+}
+// end tests -- do not delete; needed for coverage.
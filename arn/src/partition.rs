@@ -0,0 +1,446 @@
+use {
+    crate::{
+        utils::{validate_partition, validate_region},
+        ArnError,
+    },
+    regex::Regex,
+    serde::Deserialize,
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        error::Error,
+        fmt::{Display, Formatter, Result as FmtResult},
+        io::{Error as IoError, Read},
+    },
+};
+
+/// Built-in partition definitions: partition id, DNS suffix, region regex, and known regions.
+const BUILTIN_PARTITIONS: &[(&str, &str, &str, &[&str])] = &[
+    (
+        "aws",
+        "amazonaws.com",
+        r"^(us|eu|ap|sa|ca|me|af|il|mx)\-\w+\-\d+$",
+        &[
+            "af-south-1",
+            "ap-east-1",
+            "ap-northeast-1",
+            "ap-northeast-2",
+            "ap-northeast-3",
+            "ap-south-1",
+            "ap-south-2",
+            "ap-southeast-1",
+            "ap-southeast-2",
+            "ap-southeast-3",
+            "ap-southeast-4",
+            "ap-southeast-5",
+            "ap-southeast-7",
+            "ca-central-1",
+            "ca-west-1",
+            "eu-central-1",
+            "eu-central-2",
+            "eu-north-1",
+            "eu-south-1",
+            "eu-south-2",
+            "eu-west-1",
+            "eu-west-2",
+            "eu-west-3",
+            "il-central-1",
+            "me-central-1",
+            "me-south-1",
+            "mx-central-1",
+            "sa-east-1",
+            "us-east-1",
+            "us-east-2",
+            "us-west-1",
+            "us-west-2",
+        ],
+    ),
+    ("aws-cn", "amazonaws.com.cn", r"^cn\-\w+\-\d+$", &["cn-north-1", "cn-northwest-1"]),
+    ("aws-us-gov", "amazonaws.com", r"^us\-gov\-\w+\-\d+$", &["us-gov-east-1", "us-gov-west-1"]),
+    ("aws-iso", "c2s.ic.gov", r"^us\-iso\-\w+\-\d+$", &["us-iso-east-1", "us-iso-west-1"]),
+    ("aws-iso-b", "sc2s.sgov.gov", r"^us\-isob\-\w+\-\d+$", &["us-isob-east-1"]),
+];
+
+/// Metadata about a partition: its DNS suffix and the regions it contains.
+///
+/// [Partition] objects are immutable.
+#[derive(Clone, Debug)]
+pub struct Partition {
+    id: String,
+    dns_suffix: String,
+    region_regex: Regex,
+    regions: BTreeSet<String>,
+}
+
+impl Partition {
+    /// Create a new partition definition.
+    ///
+    /// * `id` - The partition name used in ARNs, such as `aws` or `aws-cn`. This must meet the rules specified in
+    ///   [validate_partition].
+    /// * `dns_suffix` - The DNS suffix for service endpoints in the partition, such as `amazonaws.com`.
+    /// * `region_regex` - A regular expression matching region names in the partition, including regions not yet
+    ///   listed in `regions`.
+    /// * `regions` - The known regions in the partition. Each must meet the rules specified in [validate_region].
+    ///
+    /// # Errors
+    ///
+    /// * If the partition id is invalid, [PartitionLoadError::Arn] wrapping [ArnError::InvalidPartition] is returned.
+    /// * If a region is invalid, [PartitionLoadError::Arn] wrapping [ArnError::InvalidRegion] is returned.
+    /// * If the region regex is invalid, [PartitionLoadError::Regex] is returned.
+    pub fn new<I, S>(id: &str, dns_suffix: &str, region_regex: &str, regions: I) -> Result<Self, PartitionLoadError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        validate_partition(id)?;
+        let region_regex = Regex::new(region_regex)?;
+        let mut region_set = BTreeSet::new();
+
+        for region in regions {
+            let region = region.into();
+            validate_region(&region)?;
+            region_set.insert(region);
+        }
+
+        Ok(Self {
+            id: id.to_string(),
+            dns_suffix: dns_suffix.to_string(),
+            region_regex,
+            regions: region_set,
+        })
+    }
+
+    /// Retrieve the partition name used in ARNs.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Retrieve the DNS suffix for service endpoints in the partition.
+    #[inline]
+    pub fn dns_suffix(&self) -> &str {
+        &self.dns_suffix
+    }
+
+    /// Retrieve the regular expression matching region names in the partition.
+    #[inline]
+    pub fn region_regex(&self) -> &Regex {
+        &self.region_regex
+    }
+
+    /// Iterate over the known regions in the partition, in sorted order.
+    #[inline]
+    pub fn regions(&self) -> impl Iterator<Item = &str> {
+        self.regions.iter().map(String::as_str)
+    }
+
+    /// Indicates whether `region` is one of the known regions in the partition.
+    #[inline]
+    pub fn has_region(&self, region: &str) -> bool {
+        self.regions.contains(region)
+    }
+
+    /// Indicates whether `region` belongs to this partition: either it is a known region, or it matches the
+    /// partition's region regex.
+    #[inline]
+    pub fn matches_region(&self, region: &str) -> bool {
+        self.has_region(region) || self.region_regex.is_match(region)
+    }
+}
+
+/// A registry of [Partition] definitions, keyed by partition id.
+///
+/// [PartitionRegistry::builtin] provides the AWS partitions; custom partitions can be added with
+/// [PartitionRegistry::insert] or loaded from an `endpoints.json`-style document with
+/// [PartitionRegistry::load_endpoints_json].
+#[derive(Clone, Debug, Default)]
+pub struct PartitionRegistry {
+    partitions: BTreeMap<String, Partition>,
+}
+
+impl PartitionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry containing the built-in AWS partitions: `aws`, `aws-cn`, `aws-us-gov`, `aws-iso`, and
+    /// `aws-iso-b`.
+    pub fn builtin() -> Self {
+        let mut result = Self::new();
+        for (id, dns_suffix, region_regex, regions) in BUILTIN_PARTITIONS {
+            // The built-in partitions are known to be valid.
+            result.insert(Partition::new(id, dns_suffix, region_regex, regions.iter().copied()).unwrap());
+        }
+        result
+    }
+
+    /// Add a partition to the registry, returning the previous definition with the same id, if any.
+    pub fn insert(&mut self, partition: Partition) -> Option<Partition> {
+        self.partitions.insert(partition.id.clone(), partition)
+    }
+
+    /// Retrieve the partition with the specified id.
+    #[inline]
+    pub fn get(&self, id: &str) -> Option<&Partition> {
+        self.partitions.get(id)
+    }
+
+    /// Iterate over the partitions in the registry, ordered by id.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Partition> {
+        self.partitions.values()
+    }
+
+    /// Find the partition a region belongs to.
+    ///
+    /// Partitions listing the region as a known region take precedence over partitions whose region regex matches.
+    pub fn partition_for_region(&self, region: &str) -> Option<&Partition> {
+        self.iter().find(|p| p.has_region(region)).or_else(|| self.iter().find(|p| p.region_regex.is_match(region)))
+    }
+
+    /// Load partitions from an `endpoints.json`-style document, adding them to (or replacing them in) this registry.
+    ///
+    /// The document must be a JSON object with a `partitions` array. Each partition must have `partition`,
+    /// `dnsSuffix`, `regionRegex`, and `regions` keys; `regions` is an object keyed by region name. Other keys (such
+    /// as `services`) are ignored.
+    ///
+    /// # Errors
+    ///
+    /// If the document is malformed or a partition definition is invalid, a [PartitionLoadError] is returned and the
+    /// registry is left unchanged.
+    pub fn load_endpoints_json(&mut self, json: &str) -> Result<(), PartitionLoadError> {
+        let doc: EndpointsDocument = serde_json::from_str(json)?;
+        self.load_document(doc)
+    }
+
+    /// Load partitions from a reader producing an `endpoints.json`-style document. See
+    /// [PartitionRegistry::load_endpoints_json].
+    pub fn load_endpoints_reader<R: Read>(&mut self, reader: R) -> Result<(), PartitionLoadError> {
+        let doc: EndpointsDocument = serde_json::from_reader(reader)?;
+        self.load_document(doc)
+    }
+
+    fn load_document(&mut self, doc: EndpointsDocument) -> Result<(), PartitionLoadError> {
+        let mut partitions = Vec::with_capacity(doc.partitions.len());
+        for p in doc.partitions {
+            partitions.push(Partition::new(&p.partition, &p.dns_suffix, &p.region_regex, p.regions.into_keys())?);
+        }
+
+        for partition in partitions {
+            self.insert(partition);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct EndpointsDocument {
+    partitions: Vec<EndpointsPartition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointsPartition {
+    partition: String,
+    dns_suffix: String,
+    region_regex: String,
+    regions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Errors that can be raised while loading partition definitions.
+#[derive(Debug)]
+pub enum PartitionLoadError {
+    /// A partition id or region name is invalid.
+    Arn(ArnError),
+
+    /// The document could not be read.
+    Io(IoError),
+
+    /// The document is not a valid `endpoints.json`-style document.
+    Json(serde_json::Error),
+
+    /// A region regex is invalid.
+    Regex(regex::Error),
+}
+
+impl Error for PartitionLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Arn(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Json(e) => Some(e),
+            Self::Regex(e) => Some(e),
+        }
+    }
+}
+
+impl Display for PartitionLoadError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Arn(e) => Display::fmt(e, f),
+            Self::Io(e) => write!(f, "Unable to read partition definitions: {e}"),
+            Self::Json(e) => write!(f, "Invalid partition definitions: {e}"),
+            Self::Regex(e) => write!(f, "Invalid region regex: {e}"),
+        }
+    }
+}
+
+impl From<ArnError> for PartitionLoadError {
+    fn from(err: ArnError) -> Self {
+        Self::Arn(err)
+    }
+}
+
+impl From<serde_json::Error> for PartitionLoadError {
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            Self::Io(err.into())
+        } else {
+            Self::Json(err)
+        }
+    }
+}
+
+impl From<regex::Error> for PartitionLoadError {
+    fn from(err: regex::Error) -> Self {
+        Self::Regex(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::{Partition, PartitionLoadError, PartitionRegistry},
+        crate::{Arn, ArnError},
+        pretty_assertions::assert_eq,
+        std::{error::Error, str::FromStr},
+    };
+
+    #[test]
+    fn check_builtin() {
+        let registry = PartitionRegistry::builtin();
+        let ids: Vec<&str> = registry.iter().map(|p| p.id()).collect();
+        assert_eq!(ids, vec!["aws", "aws-cn", "aws-iso", "aws-iso-b", "aws-us-gov"]);
+
+        let aws = registry.get("aws").unwrap();
+        assert_eq!(aws.dns_suffix(), "amazonaws.com");
+        assert!(aws.has_region("us-east-1"));
+        assert!(!aws.has_region("us-east-9"));
+        assert!(aws.matches_region("us-east-9"));
+        assert!(!aws.matches_region("cn-north-1"));
+        assert!(aws.regions().any(|r| r == "eu-west-1"));
+        assert_eq!(aws.region_regex().as_str(), r"^(us|eu|ap|sa|ca|me|af|il|mx)\-\w+\-\d+$");
+
+        assert_eq!(registry.get("aws-cn").unwrap().dns_suffix(), "amazonaws.com.cn");
+        assert_eq!(registry.get("aws-iso").unwrap().dns_suffix(), "c2s.ic.gov");
+        assert_eq!(registry.get("aws-iso-b").unwrap().dns_suffix(), "sc2s.sgov.gov");
+        assert!(registry.get("aws-us-gov").unwrap().has_region("us-gov-west-1"));
+        assert!(registry.get("local").is_none());
+
+        assert_eq!(registry.partition_for_region("us-gov-west-1").unwrap().id(), "aws-us-gov");
+        assert_eq!(registry.partition_for_region("cn-north-1").unwrap().id(), "aws-cn");
+        assert_eq!(registry.partition_for_region("us-isob-east-1").unwrap().id(), "aws-iso-b");
+        assert_eq!(registry.partition_for_region("ap-southeast-9").unwrap().id(), "aws");
+        assert!(registry.partition_for_region("local").is_none());
+    }
+
+    #[test]
+    fn check_validate_in() {
+        let registry = PartitionRegistry::builtin();
+
+        Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap().validate_in(&registry).unwrap();
+        Arn::from_str("arn:aws-cn:s3:::bucket").unwrap().validate_in(&registry).unwrap();
+        Arn::from_str("arn:aws-cn:ec2:cn-north-1:123456789012:instance/i-1").unwrap().validate_in(&registry).unwrap();
+
+        let err = Arn::from_str("arn:aws-cn:s3:us-east-1:123456789012:bucket").unwrap().validate_in(&registry);
+        assert_eq!(err.unwrap_err(), ArnError::InvalidRegion("us-east-1".to_string()));
+
+        let err = Arn::from_str("arn:local:s3:local:123456789012:bucket").unwrap().validate_in(&registry);
+        assert_eq!(err.unwrap_err(), ArnError::InvalidPartition("local".to_string()));
+    }
+
+    #[test]
+    fn check_custom_partitions() {
+        let mut registry = PartitionRegistry::new();
+        assert!(registry.iter().next().is_none());
+
+        let local = Partition::new("local", "scratchstack.local", r"^local(\-\w+\-\d+)?$", ["local"]).unwrap();
+        assert!(registry.insert(local.clone()).is_none());
+        assert!(registry.insert(local).is_some());
+
+        Arn::from_str("arn:local:s3:local::bucket").unwrap().validate_in(&registry).unwrap();
+        Arn::from_str("arn:local:s3:local-east-1::bucket").unwrap().validate_in(&registry).unwrap();
+        let err = Arn::from_str("arn:local:s3:us-east-1::bucket").unwrap().validate_in(&registry).unwrap_err();
+        assert_eq!(err, ArnError::InvalidRegion("us-east-1".to_string()));
+
+        let err = Partition::new("Local", "x", ".*", ["local"]).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid partition: "Local""#);
+        assert!(err.source().is_some());
+
+        let err = Partition::new("local", "x", ".*", ["local-"]).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "local-""#);
+
+        let err = Partition::new("local", "x", "(", ["local"]).unwrap_err();
+        assert!(matches!(err, PartitionLoadError::Regex(_)));
+        assert!(err.to_string().starts_with("Invalid region regex: "));
+        let _ = format!("{err:?}");
+    }
+
+    #[test]
+    fn check_load_endpoints() {
+        let json = r#"{
+            "partitions": [
+                {
+                    "partition": "intranet",
+                    "partitionName": "Intranet",
+                    "dnsSuffix": "cloud.example.com",
+                    "regionRegex": "^corp\\-\\w+\\-\\d+$",
+                    "regions": {
+                        "corp-east-1": { "description": "Corporate East" },
+                        "corp-west-1": { "description": "Corporate West" }
+                    },
+                    "services": {}
+                }
+            ],
+            "version": 3
+        }"#;
+
+        let mut registry = PartitionRegistry::builtin();
+        registry.load_endpoints_json(json).unwrap();
+        let intranet = registry.get("intranet").unwrap();
+        assert_eq!(intranet.dns_suffix(), "cloud.example.com");
+        assert_eq!(intranet.regions().collect::<Vec<_>>(), vec!["corp-east-1", "corp-west-1"]);
+        assert!(registry.get("aws").is_some());
+
+        let mut registry = PartitionRegistry::new();
+        registry.load_endpoints_reader(json.as_bytes()).unwrap();
+        Arn::from_str("arn:intranet:s3:corp-east-1::bucket").unwrap().validate_in(&registry).unwrap();
+
+        let err = registry.load_endpoints_json(r#"{"partitions": [{}]}"#).unwrap_err();
+        assert!(matches!(err, PartitionLoadError::Json(_)));
+        assert!(err.to_string().starts_with("Invalid partition definitions: "));
+        assert!(err.source().is_some());
+
+        // A bad partition leaves the registry unchanged.
+        let bad = json.replace("corp-west-1", "corp-west-");
+        let bad = bad.replace("intranet", "intranet2");
+        let err = registry.load_endpoints_json(&bad).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "corp-west-""#);
+        assert!(registry.get("intranet2").is_none());
+
+        let err = registry.load_endpoints_reader(FailingReader).unwrap_err();
+        assert!(matches!(err, PartitionLoadError::Io(_)));
+        assert!(err.to_string().starts_with("Unable to read partition definitions: "));
+        assert!(err.source().is_some());
+    }
+
+    struct FailingReader;
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("read failed"))
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
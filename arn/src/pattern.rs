@@ -0,0 +1,371 @@
+use {
+    crate::{Arn, ArnError},
+    alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    },
+    core::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+    serde::{de, Deserialize, Serialize},
+};
+
+/// A pattern matching a single component of an ARN.
+///
+/// Patterns may contain the wildcards `*` (matching zero or more characters) and `?` (matching exactly one
+/// character). Wildcards never match across component boundaries; however, within the resource component, they do
+/// match `/` and `:` characters.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ArnSegmentPattern {
+    /// Matches only an empty component.
+    Empty,
+
+    /// Matches any value, including an empty component. This is the pattern `*`.
+    Any,
+
+    /// Matches the exact value specified.
+    Exact(String),
+
+    /// Matches a value using `*` and `?` wildcards.
+    Wildcard(String),
+}
+
+impl ArnSegmentPattern {
+    /// Create a new segment pattern from the specified string.
+    pub fn new(pattern: &str) -> Self {
+        if pattern.is_empty() {
+            Self::Empty
+        } else if pattern == "*" {
+            Self::Any
+        } else if pattern.contains(['*', '?']) {
+            Self::Wildcard(pattern.to_string())
+        } else {
+            Self::Exact(pattern.to_string())
+        }
+    }
+
+    /// Indicates whether this segment pattern matches the specified value.
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Empty => value.is_empty(),
+            Self::Any => true,
+            Self::Exact(exact) => exact == value,
+            Self::Wildcard(pattern) => wildcard_match(pattern, value),
+        }
+    }
+
+    /// Returns the pattern as a string.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Empty => "",
+            Self::Any => "*",
+            Self::Exact(s) | Self::Wildcard(s) => s.as_str(),
+        }
+    }
+
+    /// Returns the literal prefix every matching value must start with, and whether the value must equal it exactly.
+    pub(crate) fn literal_prefix(&self) -> (&str, bool) {
+        match self {
+            Self::Empty => ("", true),
+            Self::Any => ("", false),
+            Self::Exact(s) => (s.as_str(), true),
+            Self::Wildcard(s) => (&s[..s.find(['*', '?']).unwrap_or(s.len())], false),
+        }
+    }
+}
+
+impl Display for ArnSegmentPattern {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Match `value` against a glob `pattern` containing `*` and `?` wildcards.
+///
+/// This uses the standard greedy algorithm with a single backtrack point; it runs in O(`pattern.len()` *
+/// `value.len()`) time in the worst case and does not allocate.
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    // The pattern position immediately after the last `*` seen, along with the value position it was matched at.
+    let mut backtrack: Option<(usize, usize)> = None;
+    let mut p_pos = 0;
+    let mut v_pos = 0;
+
+    loop {
+        let p_char = pattern[p_pos..].chars().next();
+        let v_char = value[v_pos..].chars().next();
+
+        match (p_char, v_char) {
+            (Some('*'), _) => {
+                p_pos += 1;
+                backtrack = Some((p_pos, v_pos));
+                continue;
+            }
+            (Some('?'), Some(vc)) => {
+                p_pos += 1;
+                v_pos += vc.len_utf8();
+                continue;
+            }
+            (Some(pc), Some(vc)) if pc == vc => {
+                p_pos += pc.len_utf8();
+                v_pos += vc.len_utf8();
+                continue;
+            }
+            (None, None) => return true,
+            _ => (),
+        }
+
+        // Mismatch; try to extend the last `*` by one more character.
+        match backtrack {
+            Some((bp, bv)) => match value[bv..].chars().next() {
+                Some(vc) => {
+                    let bv = bv + vc.len_utf8();
+                    backtrack = Some((bp, bv));
+                    p_pos = bp;
+                    v_pos = bv;
+                }
+                None => return false,
+            },
+            None => return false,
+        }
+    }
+}
+
+/// A pattern matching Amazon Resource Names (ARNs), as used in the resource statements of IAM Aspen policies.
+///
+/// Each of the five components (partition, service, region, account ID, and resource) is an
+/// [ArnSegmentPattern] that may contain `*` and `?` wildcards. For example, `arn:aws:ec?:us-west-*:*:instance/*`
+/// matches any EC2 instance in a `us-west-` region of any account.
+///
+/// [ArnPattern] objects are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ArnPattern {
+    partition: ArnSegmentPattern,
+    service: ArnSegmentPattern,
+    region: ArnSegmentPattern,
+    account_id: ArnSegmentPattern,
+    resource: ArnSegmentPattern,
+}
+
+impl ArnPattern {
+    /// Create a new ARN pattern from the specified component patterns.
+    ///
+    /// Components are not validated beyond wildcard parsing: IAM accepts any string in the components of a resource
+    /// statement, and a component that cannot match a valid [Arn] simply never matches.
+    pub fn new(partition: &str, service: &str, region: &str, account_id: &str, resource: &str) -> Self {
+        Self {
+            partition: ArnSegmentPattern::new(partition),
+            service: ArnSegmentPattern::new(service),
+            region: ArnSegmentPattern::new(region),
+            account_id: ArnSegmentPattern::new(account_id),
+            resource: ArnSegmentPattern::new(resource),
+        }
+    }
+
+    /// Retrieve the pattern for the partition.
+    #[inline]
+    pub fn partition(&self) -> &ArnSegmentPattern {
+        &self.partition
+    }
+
+    /// Retrieve the pattern for the service.
+    #[inline]
+    pub fn service(&self) -> &ArnSegmentPattern {
+        &self.service
+    }
+
+    /// Retrieve the pattern for the region.
+    #[inline]
+    pub fn region(&self) -> &ArnSegmentPattern {
+        &self.region
+    }
+
+    /// Retrieve the pattern for the account ID.
+    #[inline]
+    pub fn account_id(&self) -> &ArnSegmentPattern {
+        &self.account_id
+    }
+
+    /// Retrieve the pattern for the resource.
+    #[inline]
+    pub fn resource(&self) -> &ArnSegmentPattern {
+        &self.resource
+    }
+
+    /// Indicates whether this pattern matches the specified [Arn].
+    ///
+    /// Each component of the ARN is matched against the corresponding component pattern; all must match.
+    pub fn matches(&self, arn: &Arn) -> bool {
+        self.partition.matches(arn.partition())
+            && self.service.matches(arn.service())
+            && self.region.matches(arn.region())
+            && self.account_id.matches(arn.account_id())
+            && self.resource.matches(arn.resource())
+    }
+}
+
+impl Display for ArnPattern {
+    /// Return the ARN pattern.
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:{}:{}:{}:{}", self.partition, self.service, self.region, self.account_id, self.resource)
+    }
+}
+
+/// Parse a string into an [ArnPattern].
+impl FromStr for ArnPattern {
+    /// [ArnError] is returned if the string is not a valid ARN pattern.
+    type Err = ArnError;
+
+    /// Parse an ARN pattern from a string.
+    ///
+    /// # Errors
+    ///
+    /// * If the pattern is not composed of 6 colon-separated components, [ArnError::InvalidArn] is returned.
+    /// * If the pattern does not start with `arn:`, [ArnError::InvalidScheme] is returned.
+    fn from_str(s: &str) -> Result<Self, ArnError> {
+        let parts: Vec<&str> = s.splitn(6, ':').collect();
+        if parts.len() != 6 {
+            return Err(ArnError::InvalidArn(s.to_string()));
+        }
+
+        if parts[0] != "arn" {
+            return Err(ArnError::InvalidScheme(parts[0].to_string()));
+        }
+
+        Ok(Self::new(parts[1], parts[2], parts[3], parts[4], parts[5]))
+    }
+}
+
+impl<'de> Deserialize<'de> for ArnPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for ArnPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::{wildcard_match, ArnPattern, ArnSegmentPattern},
+        crate::{Arn, ArnError},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_segment_kinds() {
+        assert_eq!(ArnSegmentPattern::new(""), ArnSegmentPattern::Empty);
+        assert_eq!(ArnSegmentPattern::new("*"), ArnSegmentPattern::Any);
+        assert_eq!(ArnSegmentPattern::new("ec2"), ArnSegmentPattern::Exact("ec2".to_string()));
+        assert_eq!(ArnSegmentPattern::new("ec?"), ArnSegmentPattern::Wildcard("ec?".to_string()));
+        assert_eq!(ArnSegmentPattern::new("**"), ArnSegmentPattern::Wildcard("**".to_string()));
+
+        assert!(ArnSegmentPattern::Empty.matches(""));
+        assert!(!ArnSegmentPattern::Empty.matches("x"));
+        assert!(ArnSegmentPattern::Any.matches(""));
+        assert!(ArnSegmentPattern::Any.matches("anything/at:all"));
+        assert!(!ArnSegmentPattern::new("ec2").matches("ec22"));
+
+        for s in ["", "*", "ec2", "ec?"] {
+            assert_eq!(ArnSegmentPattern::new(s).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn check_wildcard_match() {
+        assert!(wildcard_match("a*", "a"));
+        assert!(wildcard_match("a*", "abc"));
+        assert!(!wildcard_match("a*", "ba"));
+        assert!(wildcard_match("*c", "abc"));
+        assert!(wildcard_match("a?c", "abc"));
+        assert!(!wildcard_match("a?c", "ac"));
+        assert!(!wildcard_match("a?c", "abbc"));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(wildcard_match("a*b*c", "abbbc"));
+        assert!(!wildcard_match("a*b*c", "axxbyy"));
+        assert!(wildcard_match("***", ""));
+        assert!(wildcard_match("?*", "x"));
+        assert!(!wildcard_match("?*", ""));
+        assert!(wildcard_match("日本-*-?", "日本-東京-1"));
+        assert!(wildcard_match("日?-東京-1", "日本-東京-1"));
+        assert!(!wildcard_match("日??-東京-1", "日本-東京-1"));
+    }
+
+    #[test]
+    fn check_pattern_matches() {
+        let pattern = ArnPattern::from_str("arn:aws:ec?:us-west-*:*:instance/*").unwrap();
+        let arn = Arn::from_str("arn:aws:ec2:us-west-2:123456789012:instance/i-1234567890abcdef0").unwrap();
+        assert!(pattern.matches(&arn));
+
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1234567890abcdef0").unwrap();
+        assert!(!pattern.matches(&arn));
+
+        let arn = Arn::from_str("arn:aws:ecs:us-west-2:123456789012:instance/i-1234567890abcdef0").unwrap();
+        assert!(pattern.matches(&arn));
+
+        let arn = Arn::from_str("arn:aws:ec2:us-west-2:123456789012:volume/vol-1234567890abcdef0").unwrap();
+        assert!(!pattern.matches(&arn));
+
+        // The resource wildcard crosses `/` and `:`.
+        let pattern = ArnPattern::from_str("arn:aws:logs:*:*:log-group:*").unwrap();
+        let arn = Arn::from_str("arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/x:*").unwrap();
+        assert!(pattern.matches(&arn));
+
+        // `*` matches empty components.
+        let pattern = ArnPattern::from_str("arn:aws:s3:*:*:my-bucket/*").unwrap();
+        let arn = Arn::from_str("arn:aws:s3:::my-bucket/a/b/c").unwrap();
+        assert!(pattern.matches(&arn));
+
+        // Empty components only match empty components.
+        let pattern = ArnPattern::from_str("arn:aws:s3:::my-bucket").unwrap();
+        assert!(pattern.matches(&Arn::from_str("arn:aws:s3:::my-bucket").unwrap()));
+        assert!(!pattern.matches(&Arn::from_str("arn:aws:s3:us-east-1::my-bucket").unwrap()));
+
+        // Wildcards do not cross component boundaries.
+        let pattern = ArnPattern::from_str("arn:aws:*:123456789012:*:*").unwrap();
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        assert!(!pattern.matches(&arn));
+        assert_eq!(pattern.region().as_str(), "123456789012");
+        assert_eq!(pattern.account_id(), &ArnSegmentPattern::Any);
+
+        let pattern = ArnPattern::from_str("arn:*:*:*:*:*").unwrap();
+        assert!(pattern.matches(&arn));
+        assert_eq!(pattern.partition(), &ArnSegmentPattern::Any);
+        assert_eq!(pattern.service(), &ArnSegmentPattern::Any);
+        assert_eq!(pattern.resource(), &ArnSegmentPattern::Any);
+    }
+
+    #[test]
+    fn check_pattern_parse() {
+        let pattern = ArnPattern::from_str("arn:aws:ec?:us-west-*:*:instance/*").unwrap();
+        assert_eq!(pattern.to_string(), "arn:aws:ec?:us-west-*:*:instance/*");
+        assert_eq!(pattern, pattern.clone());
+        let _ = format!("{pattern:?}");
+
+        assert_eq!(ArnPattern::from_str("arn:aws:ec2").unwrap_err(), ArnError::InvalidArn("arn:aws:ec2".to_string()));
+        assert_eq!(ArnPattern::from_str("arm:*:*:*:*:*").unwrap_err(), ArnError::InvalidScheme("arm".to_string()));
+    }
+
+    #[test]
+    fn check_serialization() {
+        let pattern: ArnPattern = serde_json::from_str(r#""arn:aws:s3:::bucket/*""#).unwrap();
+        assert_eq!(pattern.resource(), &ArnSegmentPattern::Wildcard("bucket/*".to_string()));
+        assert_eq!(serde_json::to_string(&pattern).unwrap(), r#""arn:aws:s3:::bucket/*""#);
+
+        let err = serde_json::from_str::<ArnPattern>(r#""arn:aws""#).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid ARN: "arn:aws""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,240 @@
+use {
+    crate::{utils, ArnError},
+    alloc::string::ToString,
+};
+
+/// A set of rules for validating the components of an ARN.
+///
+/// Each method has a default implementation that applies the rules of the corresponding validator in
+/// [utils](crate::utils); these are the rules used by [ScratchstackRelaxed]. Custom profiles need only override the
+/// components whose rules differ. For example, a deployment using 10-digit account ids might use:
+///
+/// ```
+/// # use scratchstack_arn::{utils::validate_account_id_digits, Arn, ArnError, ValidationProfile};
+/// struct OnPrem;
+///
+/// impl ValidationProfile for OnPrem {
+///     fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+///         validate_account_id_digits(account_id, 10)
+///     }
+/// }
+///
+/// let arn = Arn::from_str_with_profile("arn:aws:s3::1234567890:bucket", &OnPrem).unwrap();
+/// assert_eq!(arn.account_id(), "1234567890");
+/// ```
+pub trait ValidationProfile {
+    /// Verify that a partition name meets the requirements of this profile. The default implementation is
+    /// [utils::validate_partition].
+    fn validate_partition(&self, partition: &str) -> Result<(), ArnError> {
+        utils::validate_partition(partition)
+    }
+
+    /// Verify that a service name meets the requirements of this profile. The default implementation is
+    /// [utils::validate_service].
+    fn validate_service(&self, service: &str) -> Result<(), ArnError> {
+        utils::validate_service(service)
+    }
+
+    /// Verify that a non-empty region name meets the requirements of this profile. The default implementation is
+    /// [utils::validate_region].
+    fn validate_region(&self, region: &str) -> Result<(), ArnError> {
+        utils::validate_region(region)
+    }
+
+    /// Verify that a non-empty account id meets the requirements of this profile. The default implementation is
+    /// [utils::validate_account_id].
+    fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+        utils::validate_account_id(account_id)
+    }
+
+    /// Verify that a resource meets the requirements of this profile. The default implementation is
+    /// [utils::validate_resource].
+    fn validate_resource(&self, resource: &str) -> Result<(), ArnError> {
+        utils::validate_resource(resource)
+    }
+
+    /// The maximum length of an ARN, in characters, or `None` if ARNs may be of any length. The default is
+    /// [utils::MAX_ARN_LENGTH]. ARNs exceeding this length are rejected with [ArnError::InvalidResource].
+    fn max_arn_length(&self) -> Option<usize> {
+        Some(utils::MAX_ARN_LENGTH)
+    }
+}
+
+/// The validation rules used by default in Scratchstack.
+///
+/// These are the rules of the validators in [utils](crate::utils): partitions, services, and regions may contain
+/// Unicode non-uppercase alphabetic characters (e.g. `aws-中国` or `日本-東京-1`), regions may be `local` or have a
+/// local region appended (e.g. `us-east-1-bos-1`), and account ids must be 12 ASCII digits or `aws`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ScratchstackRelaxed;
+
+impl ValidationProfile for ScratchstackRelaxed {}
+
+/// Validation rules that reject anything AWS itself would reject.
+///
+/// In addition to the rules of [ScratchstackRelaxed], this requires:
+///
+/// * Partitions, services, and regions must be ASCII.
+/// * Regions must be in the form `name-name-digits` (e.g. `us-east-1` or `us-gov-west-1`). The special region `local`
+///   and local regions appended to a region (e.g. `us-east-1-bos-1`) are rejected.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AwsStrict;
+
+impl ValidationProfile for AwsStrict {
+    fn validate_partition(&self, partition: &str) -> Result<(), ArnError> {
+        if !partition.is_ascii() {
+            return Err(ArnError::InvalidPartition(partition.to_string()));
+        }
+
+        utils::validate_partition(partition)
+    }
+
+    fn validate_service(&self, service: &str) -> Result<(), ArnError> {
+        if !service.is_ascii() {
+            return Err(ArnError::InvalidService(service.to_string()));
+        }
+
+        utils::validate_service(service)
+    }
+
+    fn validate_region(&self, region: &str) -> Result<(), ArnError> {
+        if !region.is_ascii() || region == "local" {
+            return Err(ArnError::InvalidRegion(region.to_string()));
+        }
+
+        utils::validate_region(region)?;
+
+        // Digits may only appear at the end; otherwise, a local region has been appended.
+        if region.trim_end_matches(|c: char| c.is_ascii_digit()).contains(|c: char| c.is_ascii_digit()) {
+            return Err(ArnError::InvalidRegion(region.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// A profile that accepts any resource, for loading legacy data that predates resource validation.
+///
+/// The partition, service, region, and account id are validated by the wrapped profile, but resources may be empty,
+/// contain control characters, and make the ARN any length.
+///
+/// ```
+/// # use scratchstack_arn::{Arn, LegacyResources, ScratchstackRelaxed};
+/// let arn = Arn::new_with_profile("aws", "s3", "", "", "", &LegacyResources(ScratchstackRelaxed)).unwrap();
+/// assert_eq!(arn.resource(), "");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LegacyResources<P: ValidationProfile = ScratchstackRelaxed>(pub P);
+
+impl<P: ValidationProfile> ValidationProfile for LegacyResources<P> {
+    fn validate_partition(&self, partition: &str) -> Result<(), ArnError> {
+        self.0.validate_partition(partition)
+    }
+
+    fn validate_service(&self, service: &str) -> Result<(), ArnError> {
+        self.0.validate_service(service)
+    }
+
+    fn validate_region(&self, region: &str) -> Result<(), ArnError> {
+        self.0.validate_region(region)
+    }
+
+    fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+        self.0.validate_account_id(account_id)
+    }
+
+    fn validate_resource(&self, _resource: &str) -> Result<(), ArnError> {
+        Ok(())
+    }
+
+    fn max_arn_length(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::{AwsStrict, LegacyResources, ScratchstackRelaxed, ValidationProfile},
+        crate::{utils::validate_account_id_digits, Arn, ArnError},
+        pretty_assertions::assert_eq,
+    };
+
+    struct OnPrem;
+
+    impl ValidationProfile for OnPrem {
+        fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+            validate_account_id_digits(account_id, 10)
+        }
+    }
+
+    #[test]
+    fn check_relaxed() {
+        let arn = Arn::from_str_with_profile(
+            "arn:aws-中国:één:日本-東京-1:123456789012:instance/i-1234567890abcdef0",
+            &ScratchstackRelaxed,
+        )
+        .unwrap();
+        assert_eq!(arn.region(), "日本-東京-1");
+
+        ScratchstackRelaxed.validate_region("local").unwrap();
+        ScratchstackRelaxed.validate_region("us-east-1-bos-1").unwrap();
+        let _ = format!("{:?}", ScratchstackRelaxed);
+    }
+
+    #[test]
+    fn check_strict() {
+        let arn = Arn::from_str_with_profile("arn:aws-us-gov:ec2:us-gov-west-1:123456789012:instance/i-1", &AwsStrict)
+            .unwrap();
+        assert_eq!(arn.partition(), "aws-us-gov");
+        Arn::new_with_profile("aws", "s3", "", "", "bucket", &AwsStrict).unwrap();
+        Arn::new_with_profile("aws", "iam", "", "aws", "policy/x", &AwsStrict).unwrap();
+
+        assert_eq!(
+            AwsStrict.validate_partition("aws-中国").unwrap_err(),
+            ArnError::InvalidPartition("aws-中国".into())
+        );
+        assert_eq!(AwsStrict.validate_partition("aws-").unwrap_err(), ArnError::InvalidPartition("aws-".into()));
+        assert_eq!(AwsStrict.validate_service("één").unwrap_err(), ArnError::InvalidService("één".into()));
+        assert_eq!(AwsStrict.validate_service("Ec2").unwrap_err(), ArnError::InvalidService("Ec2".into()));
+        assert_eq!(
+            AwsStrict.validate_region("日本-東京-1").unwrap_err(),
+            ArnError::InvalidRegion("日本-東京-1".into())
+        );
+        assert_eq!(AwsStrict.validate_region("local").unwrap_err(), ArnError::InvalidRegion("local".into()));
+        assert_eq!(
+            AwsStrict.validate_region("us-east-1-bos-1").unwrap_err(),
+            ArnError::InvalidRegion("us-east-1-bos-1".into())
+        );
+        assert_eq!(AwsStrict.validate_region("us-east").unwrap_err(), ArnError::InvalidRegion("us-east".into()));
+
+        let err = Arn::from_str_with_profile("arn:aws:ec2:local:123456789012:instance/i-1", &AwsStrict).unwrap_err();
+        assert_eq!(err, ArnError::InvalidRegion("local".into()));
+    }
+
+    #[test]
+    fn check_custom() {
+        let arn = Arn::new_with_profile("aws", "s3", "", "1234567890", "bucket", &OnPrem).unwrap();
+        assert_eq!(arn.account_id(), "1234567890");
+
+        let err = Arn::new_with_profile("aws", "s3", "", "123456789012", "bucket", &OnPrem).unwrap_err();
+        assert_eq!(err, ArnError::InvalidAccountId("123456789012".into()));
+
+        let profile = LegacyResources(OnPrem);
+        let arn = Arn::new_with_profile("aws", "s3", "", "1234567890", "", &profile).unwrap();
+        assert_eq!(arn.resource(), "");
+        let err = Arn::new_with_profile("aws", "s3", "", "123456789012", "", &profile).unwrap_err();
+        assert_eq!(err, ArnError::InvalidAccountId("123456789012".into()));
+        let err = Arn::new_with_profile("Aws", "s3", "", "", "", &profile).unwrap_err();
+        assert_eq!(err, ArnError::InvalidPartition("Aws".into()));
+        let err = Arn::new_with_profile("aws", "S3", "", "", "", &profile).unwrap_err();
+        assert_eq!(err, ArnError::InvalidService("S3".into()));
+        let err = Arn::new_with_profile("aws", "s3", "us", "", "", &profile).unwrap_err();
+        assert_eq!(err, ArnError::InvalidRegion("us".into()));
+
+        // Profiles may be used as trait objects.
+        let profile: &dyn ValidationProfile = &OnPrem;
+        Arn::from_str_with_profile("arn:aws:s3:local:1234567890:bucket", profile).unwrap();
+    }
+}
+// end tests -- do not delete; needed for coverage.
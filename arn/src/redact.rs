@@ -0,0 +1,348 @@
+use {
+    crate::{scan, Arn, ScratchstackRelaxed},
+    alloc::{
+        borrow::Cow,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    },
+    core::fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hmac::{Hmac, Mac},
+    sha2::Sha256,
+};
+
+/// The account id used in place of masked account ids.
+const MASKED_ACCOUNT_ID: &str = "000000000000";
+
+/// The resource id used in place of masked resources.
+const MASKED_RESOURCE: &str = "redacted";
+
+/// How a component of an ARN is redacted by a [Redactor].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Redaction {
+    /// Leave the component unchanged.
+    Keep,
+
+    /// Replace the component with a fixed placeholder. Account ids become `000000000000`; resources keep their
+    /// resource type and have the rest replaced with `redacted` (e.g. `user/alice` becomes `user/redacted`).
+    Mask,
+
+    /// Replace the component with a pseudonym derived from an HMAC-SHA256 of the component, keyed by the
+    /// [Redactor]'s key. The same value always maps to the same pseudonym under the same key, so log lines can be
+    /// correlated without revealing the value. Account ids become 12 digits; resources keep their resource type and
+    /// have the rest replaced with `anon-` followed by 16 hex digits (e.g. `user/anon-3f2a9c0d1e4b5a67`).
+    Pseudonymize,
+}
+
+/// Redacts the account id and resource of ARNs so they can be written to shared logs.
+///
+/// Redacted ARNs are still valid ARNs. The partition, service, and region are never changed, nor are empty account ids
+/// or the account id `aws`, since these do not identify a customer. Since pseudonyms have a fixed length, a redacted
+/// ARN may be longer than the original, but it is never longer than the maximum ARN length; see [Redactor::redact].
+///
+/// ```
+/// # use scratchstack_arn::{Arn, Redaction, Redactor};
+/// # use std::str::FromStr;
+/// let redactor = Redactor::new(b"log-key", Redaction::Pseudonymize, Redaction::Mask);
+/// let arn = Arn::from_str("arn:aws:iam::123456789012:user/alice").unwrap();
+/// let redacted = redactor.redact(&arn);
+/// assert_eq!(redacted.resource(), "user/redacted");
+/// assert_eq!(redacted, redactor.redact(&arn));
+/// println!("Request from {}", redactor.display(&arn));
+/// ```
+#[derive(Clone)]
+pub struct Redactor {
+    key: Vec<u8>,
+    account_id: Redaction,
+    resource: Redaction,
+}
+
+impl Redactor {
+    /// Create a new redactor.
+    ///
+    /// * `key` - The key for pseudonyms. This should be a secret of at least 32 bytes; anyone with the key can
+    ///   confirm guesses of the original values. It is not used if neither component is pseudonymized.
+    /// * `account_id` - How to redact account ids.
+    /// * `resource` - How to redact resources.
+    pub fn new(key: &[u8], account_id: Redaction, resource: Redaction) -> Self {
+        Self {
+            key: key.to_vec(),
+            account_id,
+            resource,
+        }
+    }
+
+    /// Compute the keyed hash of `value`. The `domain` separates the pseudonyms for different components.
+    fn hash(&self, domain: &[u8], value: &str) -> [u8; 32] {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(domain);
+        mac.update(b"\0");
+        mac.update(value.as_bytes());
+        mac.finalize().into_bytes().into()
+    }
+
+    /// Redact an account id.
+    fn redact_account_id(&self, account_id: &str) -> String {
+        if account_id.is_empty() || account_id == "aws" {
+            return account_id.to_string();
+        }
+
+        match self.account_id {
+            Redaction::Keep => account_id.to_string(),
+            Redaction::Mask => MASKED_ACCOUNT_ID.to_string(),
+            Redaction::Pseudonymize => {
+                let hash = self.hash(b"account-id", account_id);
+                let value = u64::from_be_bytes(hash[..8].try_into().expect("slice is 8 bytes"));
+                format!("{:012}", value % 1_000_000_000_000)
+            }
+        }
+    }
+
+    /// Redact a resource, returning the resource type prefix (including its separator) to keep, the placeholder for
+    /// the rest, and the part of the placeholder to truncate if the ARN would otherwise be too long. Returns `None` if
+    /// resources are kept.
+    fn redact_resource<'a>(&self, resource: &'a str) -> Option<(&'a str, String, String)> {
+        let type_prefix = match resource.find(['/', ':']) {
+            Some(pos) if pos + 1 < resource.len() => &resource[..=pos],
+            _ => "",
+        };
+
+        match self.resource {
+            Redaction::Keep => None,
+            Redaction::Mask => Some((type_prefix, MASKED_RESOURCE.to_string(), MASKED_RESOURCE.to_string())),
+            Redaction::Pseudonymize => {
+                let hash = self.hash(b"resource", resource);
+                let mut digest = String::new();
+                for b in &hash[..8] {
+                    digest.push_str(&format!("{b:02x}"));
+                }
+                Some((type_prefix, format!("anon-{digest}"), digest))
+            }
+        }
+    }
+
+    /// Return a redacted copy of `arn`.
+    ///
+    /// The redacted resource may be longer than the original. If keeping the resource type would make the ARN too
+    /// long, the placeholder is used without the resource type. If that is still too long, the pseudonym's hex digits
+    /// (or, for masked resources, `redacted`) are truncated to the length of the original resource. As a last resort
+    /// for ARNs created under a custom [ValidationProfile](crate::ValidationProfile) whose account ids are shorter
+    /// than the redacted ones, the redacted account id is also truncated to the length of the original.
+    pub fn redact(&self, arn: &Arn) -> Arn {
+        let account_id = self.redact_account_id(arn.account_id());
+        let resources = match self.redact_resource(arn.resource()) {
+            None => vec![arn.resource().to_string()],
+            Some((type_prefix, placeholder, short)) => {
+                // The placeholder is ASCII, so it can be truncated at any byte.
+                let truncated = short[..short.len().min(arn.resource().chars().count())].to_string();
+                vec![format!("{type_prefix}{placeholder}"), placeholder, truncated]
+            }
+        };
+
+        let rebuild = |account_id: &str, resource: &str| {
+            arn.with_components(
+                arn.partition(),
+                arn.service(),
+                arn.region(),
+                account_id,
+                resource,
+                &ScratchstackRelaxed,
+            )
+        };
+
+        for resource in &resources {
+            if let Ok(redacted) = rebuild(&account_id, resource) {
+                return redacted;
+            }
+        }
+
+        // The last resource is no longer than the original, so with the account id truncated the same way the ARN does
+        // not grow. The redacted account id is ASCII, so it can be truncated at any byte.
+        let account_id = &account_id[..account_id.len().min(arn.account_id().chars().count())];
+        rebuild(account_id, &resources[resources.len() - 1]).expect("an ARN that does not grow is never too long")
+    }
+
+    /// Redact every ARN found in `text`, such as a log line or JSON document. ARNs are found as by [scan::find].
+    ///
+    /// If `text` contains no ARNs, it is returned without copying.
+    pub fn redact_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        scan::replace(text, |m| self.redact(m.arn()).to_string())
+    }
+
+    /// Return a wrapper that displays `arn` redacted, for use in log statements.
+    pub fn display<'a>(&'a self, arn: &'a Arn) -> RedactedArn<'a> {
+        RedactedArn {
+            redactor: self,
+            arn,
+        }
+    }
+}
+
+/// Omits the key.
+impl Debug for Redactor {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("Redactor")
+            .field("account_id", &self.account_id)
+            .field("resource", &self.resource)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An ARN that is redacted when displayed. This is created by [Redactor::display].
+#[derive(Clone, Copy, Debug)]
+pub struct RedactedArn<'a> {
+    redactor: &'a Redactor,
+    arn: &'a Arn,
+}
+
+impl Display for RedactedArn<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.redactor.redact(self.arn), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Redaction, Redactor},
+        crate::{utils::validate_account_id_digits, Arn, ArnError, ValidationProfile},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    /// A profile for deployments using 10-digit account ids.
+    struct TenDigits;
+
+    impl ValidationProfile for TenDigits {
+        fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+            validate_account_id_digits(account_id, 10)
+        }
+    }
+
+    #[test]
+    fn check_mask() {
+        let redactor = Redactor::new(b"", Redaction::Mask, Redaction::Mask);
+        let arn = Arn::from_str("arn:aws:iam::123456789012:user/alice").unwrap();
+        assert_eq!(redactor.redact(&arn).to_string(), "arn:aws:iam::000000000000:user/redacted");
+
+        let arn = Arn::from_str("arn:aws:lambda:us-east-1:123456789012:function:my-function:1").unwrap();
+        assert_eq!(redactor.redact(&arn).to_string(), "arn:aws:lambda:us-east-1:000000000000:function:redacted");
+
+        // Resources without a type, empty account ids, and the aws account are handled.
+        let arn = Arn::from_str("arn:aws:s3:::bucket").unwrap();
+        assert_eq!(redactor.redact(&arn).to_string(), "arn:aws:s3:::redacted");
+        let arn = Arn::from_str("arn:aws:s3:::bucket/").unwrap();
+        assert_eq!(redactor.redact(&arn).to_string(), "arn:aws:s3:::redacted");
+        let arn = Arn::from_str("arn:aws:iam::aws:policy/ReadOnlyAccess").unwrap();
+        assert_eq!(redactor.redact(&arn).to_string(), "arn:aws:iam::aws:policy/redacted");
+    }
+
+    #[test]
+    fn check_pseudonymize() {
+        let redactor = Redactor::new(b"key-1", Redaction::Pseudonymize, Redaction::Pseudonymize);
+        let alice = Arn::from_str("arn:aws:iam::123456789012:user/alice").unwrap();
+        let bob = Arn::from_str("arn:aws:iam::123456789012:user/bob").unwrap();
+        let other = Arn::from_str("arn:aws:iam::210987654321:user/alice").unwrap();
+
+        let r_alice = redactor.redact(&alice);
+        let r_bob = redactor.redact(&bob);
+        let r_other = redactor.redact(&other);
+
+        // Redacted ARNs are valid.
+        assert_eq!(Arn::from_str(&r_alice.to_string()).unwrap(), r_alice);
+
+        // The same account maps to the same pseudonym; different accounts and resources do not collide.
+        assert_eq!(r_alice.account_id(), r_bob.account_id());
+        assert_ne!(r_alice.account_id(), "123456789012");
+        assert_ne!(r_alice.account_id(), r_other.account_id());
+        assert_ne!(r_alice.resource(), r_bob.resource());
+        assert!(r_alice.resource().starts_with("user/anon-"));
+        assert_eq!(r_alice.resource().len(), "user/anon-".len() + 16);
+        assert_eq!(redactor.redact(&alice), r_alice);
+
+        // A different key gives different pseudonyms.
+        let redactor2 = Redactor::new(b"key-2", Redaction::Pseudonymize, Redaction::Pseudonymize);
+        assert_ne!(redactor2.redact(&alice), r_alice);
+
+        // Components may be kept.
+        let redactor = Redactor::new(b"key-1", Redaction::Keep, Redaction::Pseudonymize);
+        assert_eq!(redactor.redact(&alice).account_id(), "123456789012");
+        let redactor = Redactor::new(b"key-1", Redaction::Pseudonymize, Redaction::Keep);
+        assert_eq!(redactor.redact(&alice).resource(), "user/alice");
+        assert_eq!(redactor.redact(&alice).account_id(), r_alice.account_id());
+    }
+
+    #[test]
+    fn check_max_length() {
+        let redactor = Redactor::new(b"key-1", Redaction::Pseudonymize, Redaction::Pseudonymize);
+
+        // 2045 characters; the typed pseudonym would make this 2065 characters.
+        let arn = Arn::from_str(&format!("arn:aws:s3:::{}/b", "y".repeat(2030))).unwrap();
+        let redacted = redactor.redact(&arn);
+        assert_eq!(Arn::from_str(&redacted.to_string()).unwrap(), redacted);
+        assert!(redacted.resource().starts_with("anon-"), "{}", redacted.resource());
+        assert_eq!(redacted.resource().len(), "anon-".len() + 16);
+
+        // Exactly at the limit with a one-character resource, the placeholder is truncated.
+        let region = format!("r-{}", "1".repeat(2048 - 29));
+        let arn = Arn::new("aws", "ec2", &region, "123456789012", "x").unwrap();
+        assert_eq!(arn.to_string().chars().count(), 2048);
+        for resource in [Redaction::Mask, Redaction::Pseudonymize] {
+            let redacted = Redactor::new(b"key-1", Redaction::Pseudonymize, resource).redact(&arn);
+            assert_eq!(Arn::from_str(&redacted.to_string()).unwrap(), redacted);
+            assert_eq!(redacted.account_id().len(), 12);
+            assert_ne!(redacted.resource(), "x");
+            assert_eq!(redacted.resource().len(), 1);
+        }
+
+        // Short resources truncated at the limit still get distinct pseudonyms.
+        let region = format!("r-{}", "1".repeat(2048 - 33));
+        let short: Vec<_> = ["alpha", "bravo"]
+            .into_iter()
+            .map(|resource| {
+                let arn = Arn::new("aws", "ec2", &region, "123456789012", resource).unwrap();
+                assert_eq!(arn.to_string().chars().count(), 2048);
+                let redacted = redactor.redact(&arn);
+                assert_eq!(redacted.resource().len(), 5);
+                assert!(redacted.resource().chars().all(|c| c.is_ascii_hexdigit()), "{}", redacted.resource());
+                redacted
+            })
+            .collect();
+        assert_ne!(short[0].resource(), short[1].resource());
+
+        // Account ids shorter than the redacted ones are truncated rather than removed.
+        let region = format!("r-{}", "1".repeat(2048 - 27));
+        let arn = Arn::new_with_profile("aws", "ec2", &region, "1234567890", "x", &TenDigits).unwrap();
+        assert_eq!(arn.to_string().chars().count(), 2048);
+        for account_id in [Redaction::Mask, Redaction::Pseudonymize] {
+            let redacted = Redactor::new(b"key-1", account_id, Redaction::Mask).redact(&arn);
+            assert_eq!(redacted.to_string().chars().count(), 2048);
+            assert_eq!(redacted.account_id().len(), 10);
+            assert_ne!(redacted.account_id(), "1234567890");
+            assert_eq!(redacted.resource(), "r");
+        }
+
+        let arn = Arn::new("aws", "ec2", &format!("r-{}", "1".repeat(2048 - 29)), "123456789012", "x").unwrap();
+        let text = format!("see {arn}.");
+        let redacted = redactor.redact_text(&text);
+        assert!(Arn::from_str(&redacted[4..redacted.len() - 1]).is_ok(), "{redacted}");
+    }
+
+    #[test]
+    fn check_display() {
+        let redactor = Redactor::new(b"secret", Redaction::Mask, Redaction::Keep);
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        assert_eq!(format!("{}", redactor.display(&arn)), "arn:aws:ec2:us-east-1:000000000000:instance/i-1");
+
+        let text = "instance arn:aws:ec2:us-east-1:123456789012:instance/i-1, bucket arn:aws:s3:::bucket";
+        assert_eq!(
+            redactor.redact_text(text),
+            "instance arn:aws:ec2:us-east-1:000000000000:instance/i-1, bucket arn:aws:s3:::bucket"
+        );
+
+        let debug = format!("{redactor:?}");
+        assert!(!debug.contains("secret"), "{debug}");
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,807 @@
+use {
+    crate::{utils::validate_resource, Arn, ArnError, ScratchstackRelaxed, ValidationProfile},
+    alloc::{
+        format,
+        string::{String, ToString},
+    },
+    core::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// The scheme prefix of S3 URIs.
+const S3_URI_PREFIX: &str = "s3://";
+
+/// The maximum length of an S3 object key, in bytes.
+const MAX_KEY_LENGTH: usize = 1024;
+
+/// The suffix of multi-region access point aliases.
+const MRAP_SUFFIX: &str = ".mrap";
+
+/// An S3 bucket, with an ARN in the form `arn:partition:s3:::bucket`.
+///
+/// S3Bucket structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct S3Bucket {
+    /// The partition the bucket exists in.
+    partition: String,
+
+    /// The bucket name.
+    bucket: String,
+}
+
+impl S3Bucket {
+    /// Create an [S3Bucket] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition the bucket exists in. This must meet the rules specified in
+    ///   [utils::validate_partition](crate::utils::validate_partition) or an [ArnError::InvalidPartition] error will
+    ///   be returned.
+    /// * `bucket`: The name of the bucket. This must meet the naming rules for general purpose buckets: between 3
+    ///   and 63 ASCII lowercase letters, digits, `.`, or `-`, starting and ending with a letter or digit, with
+    ///   DNS-compatible labels, not formatted as an IPv4 address, and without a reserved prefix or suffix. Otherwise,
+    ///   an [ArnError::InvalidResource] error will be returned.
+    pub fn new(partition: &str, bucket: &str) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, bucket, &ScratchstackRelaxed)
+    }
+
+    /// Create an [S3Bucket] object, validating the partition according to the specified [ValidationProfile] instead
+    /// of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [S3Bucket::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        bucket: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        validate_bucket_name(bucket)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            bucket: bucket.into(),
+        })
+    }
+
+    /// Parse an S3 URI in the form `s3://bucket` or `s3://bucket/`. S3 URIs do not carry a partition, so this must be
+    /// supplied.
+    ///
+    /// # Errors
+    ///
+    /// * If the URI does not start with `s3://`, [ArnError::InvalidScheme] is returned.
+    /// * If the bucket name is invalid or the URI has a key, [ArnError::InvalidResource] is returned.
+    /// * If the partition is invalid, [ArnError::InvalidPartition] is returned.
+    pub fn from_uri(partition: &str, uri: &str) -> Result<Self, ArnError> {
+        let path = strip_uri_scheme(uri)?;
+        let bucket = path.strip_suffix('/').unwrap_or(path);
+        if bucket.contains('/') {
+            return Err(ArnError::InvalidResource(path.to_string()));
+        }
+
+        Self::new(partition, bucket)
+    }
+
+    /// The partition of the bucket.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The name of the bucket.
+    #[inline]
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Create an [S3Object] for `key` in this bucket.
+    ///
+    /// # Errors
+    ///
+    /// If the key is invalid, [ArnError::InvalidResource] is returned. See [S3Object::new].
+    pub fn object(&self, key: &str) -> Result<S3Object, ArnError> {
+        validate_key(key)?;
+
+        Ok(S3Object {
+            partition: self.partition.clone(),
+            bucket: self.bucket.clone(),
+            key: key.into(),
+        })
+    }
+
+    /// Return the S3 URI of the bucket in the form `s3://bucket`.
+    pub fn to_uri(&self) -> String {
+        format!("{S3_URI_PREFIX}{}", self.bucket)
+    }
+}
+
+impl From<&S3Bucket> for Arn {
+    fn from(bucket: &S3Bucket) -> Arn {
+        // Safety: The partition and bucket name were validated when the bucket was created.
+        unsafe { Arn::new_unchecked(&bucket.partition, "s3", "", "", &bucket.bucket) }
+    }
+}
+
+impl FromStr for S3Bucket {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning an [S3Bucket] if the ARN is a valid bucket ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for S3Bucket {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid S3 bucket, convert it to an [S3Bucket]; otherwise, return an [ArnError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_bucket_arn(arn)?;
+        Self::new(arn.partition(), arn.resource())
+    }
+}
+
+impl Display for S3Bucket {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:s3:::{}", self.partition, self.bucket)
+    }
+}
+
+/// An S3 object, with an ARN in the form `arn:partition:s3:::bucket/key`.
+///
+/// S3Object structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct S3Object {
+    /// The partition the object exists in.
+    partition: String,
+
+    /// The bucket name.
+    bucket: String,
+
+    /// The object key.
+    key: String,
+}
+
+impl S3Object {
+    /// Create an [S3Object] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition the object exists in. This must meet the rules specified in
+    ///   [utils::validate_partition](crate::utils::validate_partition) or an [ArnError::InvalidPartition] error will
+    ///   be returned.
+    /// * `bucket`: The name of the bucket. This must meet the rules specified in [S3Bucket::new] or an
+    ///   [ArnError::InvalidResource] error will be returned.
+    /// * `key`: The object key. This must contain between 1 and 1024 bytes and must not contain ASCII control
+    ///   characters or an [ArnError::InvalidResource] error will be returned.
+    pub fn new(partition: &str, bucket: &str, key: &str) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, bucket, key, &ScratchstackRelaxed)
+    }
+
+    /// Create an [S3Object] object, validating the partition according to the specified [ValidationProfile] instead
+    /// of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [S3Object::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        bucket: &str,
+        key: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        S3Bucket::new_with_profile(partition, bucket, profile)?.object(key)
+    }
+
+    /// Parse an S3 URI in the form `s3://bucket/key`. S3 URIs do not carry a partition, so this must be supplied.
+    ///
+    /// The key is taken as-is; it is not percent-decoded.
+    ///
+    /// # Errors
+    ///
+    /// * If the URI does not start with `s3://`, [ArnError::InvalidScheme] is returned.
+    /// * If the bucket name or key is invalid or missing, [ArnError::InvalidResource] is returned.
+    /// * If the partition is invalid, [ArnError::InvalidPartition] is returned.
+    pub fn from_uri(partition: &str, uri: &str) -> Result<Self, ArnError> {
+        let path = strip_uri_scheme(uri)?;
+        let Some((bucket, key)) = path.split_once('/') else {
+            return Err(ArnError::InvalidResource(path.to_string()));
+        };
+
+        Self::new(partition, bucket, key)
+    }
+
+    /// The partition of the object.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The name of the bucket containing the object.
+    #[inline]
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// The object key.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Return the S3 URI of the object in the form `s3://bucket/key`.
+    pub fn to_uri(&self) -> String {
+        format!("{S3_URI_PREFIX}{}/{}", self.bucket, self.key)
+    }
+}
+
+impl From<&S3Object> for Arn {
+    fn from(object: &S3Object) -> Arn {
+        // Safety: The partition, bucket name, and key were validated when the object was created. The bucket and key
+        // lengths are limited, so the ARN cannot exceed the maximum length.
+        unsafe { Arn::new_unchecked(&object.partition, "s3", "", "", &format!("{}/{}", object.bucket, object.key)) }
+    }
+}
+
+impl FromStr for S3Object {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning an [S3Object] if the ARN is a valid object ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for S3Object {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid S3 object, convert it to an [S3Object]; otherwise, return an [ArnError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        check_bucket_arn(arn)?;
+
+        let resource = arn.resource();
+        let Some((bucket, key)) = resource.split_once('/') else {
+            return Err(ArnError::InvalidResource(resource.to_string()));
+        };
+
+        Self::new(arn.partition(), bucket, key)
+    }
+}
+
+impl Display for S3Object {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:s3:::{}/{}", self.partition, self.bucket, self.key)
+    }
+}
+
+/// An S3 access point. There are three forms:
+///
+/// * Regional access points, with an ARN in the form `arn:partition:s3:region:account-id:accesspoint/name`.
+/// * Multi-region access points, which have no region and are identified by their alias, with an ARN in the form
+///   `arn:partition:s3::account-id:accesspoint/alias.mrap`.
+/// * S3 on Outposts access points, with an ARN in the form
+///   `arn:partition:s3-outposts:region:account-id:outpost/outpost-id/accesspoint/name`.
+///
+/// S3AccessPoint structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct S3AccessPoint {
+    /// The partition the access point exists in.
+    partition: String,
+
+    /// The region, or empty for multi-region access points.
+    region: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The outpost id for S3 on Outposts access points.
+    outpost_id: Option<String>,
+
+    /// The access point name, or alias for multi-region access points.
+    name: String,
+}
+
+impl S3AccessPoint {
+    /// Create an [S3AccessPoint] object for a regional or multi-region access point.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition the access point exists in. This must meet the rules specified in
+    ///   [utils::validate_partition](crate::utils::validate_partition) or an [ArnError::InvalidPartition] error will
+    ///   be returned.
+    /// * `region`: The region of the access point, or empty for a multi-region access point. If not empty, this must
+    ///   meet the rules specified in [utils::validate_region](crate::utils::validate_region) or an
+    ///   [ArnError::InvalidRegion] error will be returned.
+    /// * `account_id`: The account id. This must meet the rules specified in
+    ///   [utils::validate_account_id](crate::utils::validate_account_id) or an [ArnError::InvalidAccountId] error
+    ///   will be returned.
+    /// * `name`: For a regional access point, the name of the access point. This must contain between 3 and 50 ASCII
+    ///   lowercase letters, digits, or `-`, and must start and end with a letter or digit. For a multi-region access
+    ///   point, the alias of the access point: ASCII lowercase letters or digits followed by `.mrap`. Otherwise, an
+    ///   [ArnError::InvalidResource] error will be returned.
+    pub fn new(partition: &str, region: &str, account_id: &str, name: &str) -> Result<Self, ArnError> {
+        Self::new_with_profile(partition, region, account_id, name, &ScratchstackRelaxed)
+    }
+
+    /// Create an [S3AccessPoint] object for a regional or multi-region access point, validating the partition,
+    /// region, and account id according to the specified [ValidationProfile] instead of the default
+    /// [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [S3AccessPoint::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        name: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+
+        if region.is_empty() {
+            validate_mrap_alias(name)?;
+        } else {
+            profile.validate_region(region)?;
+            validate_access_point_name(name)?;
+        }
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            outpost_id: None,
+            name: name.into(),
+        })
+    }
+
+    /// Create an [S3AccessPoint] object for an S3 on Outposts access point.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`, `account_id`: As for [S3AccessPoint::new].
+    /// * `region`: The region of the outpost. This must meet the rules specified in
+    ///   [utils::validate_region](crate::utils::validate_region) or an [ArnError::InvalidRegion] error will be
+    ///   returned.
+    /// * `outpost_id`: The outpost id. This must be `op-` followed by ASCII lowercase letters or digits or an
+    ///   [ArnError::InvalidResource] error will be returned.
+    /// * `name`: The name of the access point, following the rules for regional access points in [S3AccessPoint::new].
+    pub fn new_outposts(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        outpost_id: &str,
+        name: &str,
+    ) -> Result<Self, ArnError> {
+        Self::new_outposts_with_profile(partition, region, account_id, outpost_id, name, &ScratchstackRelaxed)
+    }
+
+    /// Create an [S3AccessPoint] object for an S3 on Outposts access point, validating the partition, region, and
+    /// account id according to the specified [ValidationProfile] instead of the default [ScratchstackRelaxed]
+    /// profile.
+    ///
+    /// The arguments and other requirements are the same as for [S3AccessPoint::new_outposts].
+    pub fn new_outposts_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        region: &str,
+        account_id: &str,
+        outpost_id: &str,
+        name: &str,
+        profile: &P,
+    ) -> Result<Self, ArnError> {
+        profile.validate_partition(partition)?;
+        profile.validate_region(region)?;
+        profile.validate_account_id(account_id)?;
+        validate_outpost_id(outpost_id)?;
+        validate_access_point_name(name)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            outpost_id: Some(outpost_id.into()),
+            name: name.into(),
+        })
+    }
+
+    /// The partition of the access point.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The region of the access point. This is empty for multi-region access points.
+    #[inline]
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// The account ID of the access point.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The outpost id of an S3 on Outposts access point, or `None` for other access points.
+    #[inline]
+    pub fn outpost_id(&self) -> Option<&str> {
+        self.outpost_id.as_deref()
+    }
+
+    /// The name of the access point, or the alias of a multi-region access point.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Indicates whether this is a multi-region access point.
+    #[inline]
+    pub fn is_multi_region(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// The service of the access point's ARN: `s3-outposts` for S3 on Outposts access points, `s3` otherwise.
+    #[inline]
+    fn service(&self) -> &'static str {
+        if self.outpost_id.is_some() {
+            "s3-outposts"
+        } else {
+            "s3"
+        }
+    }
+
+    /// The resource of the access point's ARN.
+    fn resource(&self) -> String {
+        match &self.outpost_id {
+            Some(outpost_id) => format!("outpost/{outpost_id}/accesspoint/{}", self.name),
+            None => format!("accesspoint/{}", self.name),
+        }
+    }
+}
+
+impl From<&S3AccessPoint> for Arn {
+    fn from(access_point: &S3AccessPoint) -> Arn {
+        // Safety: All components were validated when the access point was created.
+        unsafe {
+            Arn::new_unchecked(
+                &access_point.partition,
+                access_point.service(),
+                &access_point.region,
+                &access_point.account_id,
+                &access_point.resource(),
+            )
+        }
+    }
+}
+
+impl FromStr for S3AccessPoint {
+    type Err = ArnError;
+
+    /// Parse an ARN, returning an [S3AccessPoint] if the ARN is a valid access point ARN.
+    fn from_str(arn: &str) -> Result<Self, ArnError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for S3AccessPoint {
+    type Error = ArnError;
+
+    /// If an [Arn] represents a valid S3 access point, convert it to an [S3AccessPoint]; otherwise, return an
+    /// [ArnError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let resource = arn.resource();
+
+        match arn.service() {
+            "s3" => {
+                let Some(name) = resource.strip_prefix("accesspoint/") else {
+                    return Err(ArnError::InvalidResource(resource.to_string()));
+                };
+
+                Self::new(arn.partition(), arn.region(), arn.account_id(), name)
+            }
+            "s3-outposts" => {
+                let parts = resource.strip_prefix("outpost/").and_then(|rest| rest.split_once("/accesspoint/"));
+                let Some((outpost_id, name)) = parts else {
+                    return Err(ArnError::InvalidResource(resource.to_string()));
+                };
+
+                Self::new_outposts(arn.partition(), arn.region(), arn.account_id(), outpost_id, name)
+            }
+            service => Err(ArnError::InvalidService(service.to_string())),
+        }
+    }
+}
+
+impl Display for S3AccessPoint {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:{}:{}:{}:{}", self.partition, self.service(), self.region, self.account_id, self.resource())
+    }
+}
+
+/// Verify that `arn` has the service, region, and account id of a bucket or object ARN.
+fn check_bucket_arn(arn: &Arn) -> Result<(), ArnError> {
+    if arn.service() != "s3" {
+        return Err(ArnError::InvalidService(arn.service().to_string()));
+    }
+
+    if !arn.region().is_empty() {
+        return Err(ArnError::InvalidRegion(arn.region().to_string()));
+    }
+
+    if !arn.account_id().is_empty() {
+        return Err(ArnError::InvalidAccountId(arn.account_id().to_string()));
+    }
+
+    Ok(())
+}
+
+/// Remove the `s3://` scheme from `uri`.
+fn strip_uri_scheme(uri: &str) -> Result<&str, ArnError> {
+    uri.strip_prefix(S3_URI_PREFIX).ok_or_else(|| {
+        let scheme = uri.split_once("://").map_or(uri, |(scheme, _)| scheme);
+        ArnError::InvalidScheme(scheme.to_string())
+    })
+}
+
+/// Verify that an object key is between 1 and 1024 bytes and has no control characters.
+fn validate_key(key: &str) -> Result<(), ArnError> {
+    if key.len() > MAX_KEY_LENGTH {
+        return Err(ArnError::InvalidResource(key.to_string()));
+    }
+
+    validate_resource(key)
+}
+
+/// Verify that an access point name is between 3 and 50 characters, composed of ASCII lowercase letters, digits, or
+/// `-`, and starts and ends with a letter or digit.
+fn validate_access_point_name(name: &str) -> Result<(), ArnError> {
+    let bytes = name.as_bytes();
+    if (3..=50).contains(&bytes.len())
+        && bytes.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+        && bytes[0] != b'-'
+        && bytes[bytes.len() - 1] != b'-'
+    {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidResource(name.to_string()))
+    }
+}
+
+/// Verify that an S3 bucket name meets the naming rules for general purpose buckets.
+///
+/// *   The name must contain between 3 and 63 characters.
+/// *   The name must be composed of ASCII lowercase letters, digits, `.`, or `-`, and must start and end with a letter
+///     or digit.
+/// *   Each `.`-separated label must be DNS-compatible: `..`, `.-`, and `-.` are not allowed.
+/// *   The name must not be formatted as an IPv4 address (e.g. `192.168.5.4`).
+/// *   The name must not start with the reserved prefixes `xn--` or `sthree-`, nor end with the reserved suffixes
+///     `-s3alias` or `--ol-s3`.
+///
+/// If `bucket` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidResource] error is returned.
+fn validate_bucket_name(bucket: &str) -> Result<(), ArnError> {
+    let bytes = bucket.as_bytes();
+    let valid = (3..=63).contains(&bytes.len())
+        && bytes.iter().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'.' || *b == b'-')
+        && bytes[0].is_ascii_alphanumeric()
+        && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+        && !bucket.contains("..")
+        && !bucket.contains(".-")
+        && !bucket.contains("-.")
+        && !(bucket.split('.').count() == 4
+            && bucket.split('.').all(|label| label.bytes().all(|b| b.is_ascii_digit())))
+        && !bucket.starts_with("xn--")
+        && !bucket.starts_with("sthree-")
+        && !bucket.ends_with("-s3alias")
+        && !bucket.ends_with("--ol-s3");
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ArnError::InvalidResource(bucket.to_string()))
+    }
+}
+
+/// Verify that a multi-region access point alias is composed of ASCII lowercase letters or digits followed by
+/// `.mrap`.
+fn validate_mrap_alias(alias: &str) -> Result<(), ArnError> {
+    match alias.strip_suffix(MRAP_SUFFIX) {
+        Some(id) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit()) => Ok(()),
+        _ => Err(ArnError::InvalidResource(alias.to_string())),
+    }
+}
+
+/// Verify that an outpost id is `op-` followed by ASCII lowercase letters or digits.
+fn validate_outpost_id(outpost_id: &str) -> Result<(), ArnError> {
+    match outpost_id.strip_prefix("op-") {
+        Some(id) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit()) => Ok(()),
+        _ => Err(ArnError::InvalidResource(outpost_id.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{validate_bucket_name, S3AccessPoint, S3Bucket, S3Object},
+        crate::{Arn, ArnError, AwsStrict},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_bucket() {
+        let bucket = S3Bucket::new("aws", "my-bucket").unwrap();
+        assert_eq!(bucket.partition(), "aws");
+        assert_eq!(bucket.bucket(), "my-bucket");
+        assert_eq!(bucket.to_string(), "arn:aws:s3:::my-bucket");
+        assert_eq!(bucket.to_uri(), "s3://my-bucket");
+
+        let arn: Arn = (&bucket).into();
+        assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+        assert_eq!(S3Bucket::try_from(&arn).unwrap(), bucket);
+        assert_eq!(S3Bucket::from_str("arn:aws:s3:::my-bucket").unwrap(), bucket);
+        assert_eq!(S3Bucket::from_uri("aws", "s3://my-bucket").unwrap(), bucket);
+        assert_eq!(S3Bucket::from_uri("aws", "s3://my-bucket/").unwrap(), bucket);
+
+        let err = S3Bucket::new("aws", "My_Bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("My_Bucket".to_string()));
+        let err = S3Bucket::from_str("arn:aws:s3:::my-bucket/key").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("my-bucket/key".to_string()));
+        let err = S3Bucket::from_str("arn:aws:s3:us-east-1::my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidRegion("us-east-1".to_string()));
+        let err = S3Bucket::from_str("arn:aws:s3::123456789012:my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidAccountId("123456789012".to_string()));
+        let err = S3Bucket::from_str("arn:aws:s4:::my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidService("s4".to_string()));
+        let err = S3Bucket::from_uri("aws", "s3://my-bucket/key").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("my-bucket/key".to_string()));
+        let err = S3Bucket::from_uri("aws", "https://my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidScheme("https".to_string()));
+        let err = S3Bucket::from_uri("aws", "my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidScheme("my-bucket".to_string()));
+
+        S3Bucket::new_with_profile("aws-cn", "my-bucket", &AwsStrict).unwrap();
+        let err = S3Bucket::new_with_profile("aws-中国", "my-bucket", &AwsStrict).unwrap_err();
+        assert_eq!(err, ArnError::InvalidPartition("aws-中国".to_string()));
+    }
+
+    #[test]
+    fn check_object() {
+        let object = S3Object::new("aws", "my-bucket", "path/to/key.txt").unwrap();
+        assert_eq!(object.partition(), "aws");
+        assert_eq!(object.bucket(), "my-bucket");
+        assert_eq!(object.key(), "path/to/key.txt");
+        assert_eq!(object.to_string(), "arn:aws:s3:::my-bucket/path/to/key.txt");
+        assert_eq!(object.to_uri(), "s3://my-bucket/path/to/key.txt");
+
+        let arn: Arn = (&object).into();
+        assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket/path/to/key.txt");
+        assert_eq!(S3Object::try_from(&arn).unwrap(), object);
+        assert_eq!(S3Object::from_uri("aws", "s3://my-bucket/path/to/key.txt").unwrap(), object);
+        assert_eq!(S3Bucket::new("aws", "my-bucket").unwrap().object("path/to/key.txt").unwrap(), object);
+
+        // Keys may have spaces and non-ASCII characters, and may end with a slash.
+        let object = S3Object::from_str("arn:aws:s3:::my-bucket/dir with spaces/ünïcödé/").unwrap();
+        assert_eq!(object.key(), "dir with spaces/ünïcödé/");
+        assert_eq!(S3Object::from_uri("aws", &object.to_uri()).unwrap(), object);
+
+        let err = S3Object::from_str("arn:aws:s3:::my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("my-bucket".to_string()));
+        let err = S3Object::from_str("arn:aws:s3:::my-bucket/").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("".to_string()));
+        let err = S3Object::from_str("arn:aws:s3:::-bucket/key").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("-bucket".to_string()));
+        let err = S3Object::new("aws", "my-bucket", &"k".repeat(1025)).unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("k".repeat(1025)));
+        let err = S3Object::new("aws", "my-bucket", "tab\tkey").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("tab\tkey".to_string()));
+        let err = S3Object::from_uri("aws", "s3://my-bucket").unwrap_err();
+        assert_eq!(err, ArnError::InvalidResource("my-bucket".to_string()));
+        let err = S3Object::from_uri("aws", "S3://my-bucket/key").unwrap_err();
+        assert_eq!(err, ArnError::InvalidScheme("S3".to_string()));
+        let err = S3Object::from_uri("", "s3://my-bucket/key").unwrap_err();
+        assert_eq!(err, ArnError::InvalidPartition("".to_string()));
+
+        // Buckets and objects are distinct.
+        assert!(S3Bucket::from_str(&object.to_string()).is_err());
+    }
+
+    #[test]
+    fn check_access_point() {
+        let ap = S3AccessPoint::new("aws", "us-east-1", "123456789012", "my-ap").unwrap();
+        assert_eq!(ap.partition(), "aws");
+        assert_eq!(ap.region(), "us-east-1");
+        assert_eq!(ap.account_id(), "123456789012");
+        assert_eq!(ap.outpost_id(), None);
+        assert_eq!(ap.name(), "my-ap");
+        assert!(!ap.is_multi_region());
+        assert_eq!(ap.to_string(), "arn:aws:s3:us-east-1:123456789012:accesspoint/my-ap");
+        let arn: Arn = (&ap).into();
+        assert_eq!(arn.to_string(), ap.to_string());
+        assert_eq!(S3AccessPoint::try_from(&arn).unwrap(), ap);
+
+        let mrap = S3AccessPoint::from_str("arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap").unwrap();
+        assert!(mrap.is_multi_region());
+        assert_eq!(mrap.name(), "mfzwi23gnjvgw.mrap");
+        assert_eq!(Arn::from(&mrap).to_string(), "arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap");
+
+        let outposts = S3AccessPoint::from_str(
+            "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/accesspoint/my-ap",
+        )
+        .unwrap();
+        assert_eq!(outposts.outpost_id(), Some("op-01ac5d28a6a232904"));
+        assert_eq!(outposts.name(), "my-ap");
+        assert!(!outposts.is_multi_region());
+        assert_eq!(
+            Arn::from(&outposts).to_string(),
+            "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/accesspoint/my-ap"
+        );
+        assert_eq!(
+            S3AccessPoint::new_outposts("aws", "us-west-2", "123456789012", "op-01ac5d28a6a232904", "my-ap").unwrap(),
+            outposts
+        );
+
+        for (arn, err) in [
+            ("arn:aws:s3:us-east-1:123456789012:accesspoint/MyAp", ArnError::InvalidResource("MyAp".to_string())),
+            ("arn:aws:s3:us-east-1:123456789012:accesspoint/ap", ArnError::InvalidResource("ap".to_string())),
+            ("arn:aws:s3:us-east-1:123456789012:accesspoint/-my-ap", ArnError::InvalidResource("-my-ap".to_string())),
+            (
+                "arn:aws:s3:us-east-1:123456789012:accesspoint/my-ap/object/key",
+                ArnError::InvalidResource("my-ap/object/key".to_string()),
+            ),
+            (
+                "arn:aws:s3:us-east-1:123456789012:accesspoint/alias.mrap",
+                ArnError::InvalidResource("alias.mrap".to_string()),
+            ),
+            ("arn:aws:s3::123456789012:accesspoint/my-ap", ArnError::InvalidResource("my-ap".to_string())),
+            ("arn:aws:s3::123456789012:accesspoint/.mrap", ArnError::InvalidResource(".mrap".to_string())),
+            ("arn:aws:s3:us-east-1::accesspoint/my-ap", ArnError::InvalidAccountId("".to_string())),
+            ("arn:aws:s3:us-east-1:123456789012:bucket/my-ap", ArnError::InvalidResource("bucket/my-ap".to_string())),
+            (
+                "arn:aws:s3-outposts:us-west-2:123456789012:outpost/01ac5d28a6a232904/accesspoint/my-ap",
+                ArnError::InvalidResource("01ac5d28a6a232904".to_string()),
+            ),
+            (
+                "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01ac5d28a6a232904/bucket/my-bucket",
+                ArnError::InvalidResource("outpost/op-01ac5d28a6a232904/bucket/my-bucket".to_string()),
+            ),
+            (
+                "arn:aws:s3-outposts::123456789012:outpost/op-1/accesspoint/my-ap",
+                ArnError::InvalidRegion("".to_string()),
+            ),
+            ("arn:aws:ec2:us-east-1:123456789012:accesspoint/my-ap", ArnError::InvalidService("ec2".to_string())),
+        ] {
+            assert_eq!(S3AccessPoint::from_str(arn).unwrap_err(), err, "{arn}");
+        }
+    }
+
+    #[test]
+    fn check_bucket_names() {
+        for bucket in ["abc", "my-bucket", "my.bucket.1", "1bucket", "a".repeat(63).as_str(), "192.168.5.4a"] {
+            assert!(validate_bucket_name(bucket).is_ok(), "{bucket}");
+        }
+
+        for bucket in [
+            "ab",
+            "a".repeat(64).as_str(),
+            "My-Bucket",
+            "my_bucket",
+            "-bucket",
+            "bucket-",
+            ".bucket",
+            "my..bucket",
+            "my.-bucket",
+            "my-.bucket",
+            "192.168.5.4",
+            "xn--bucket",
+            "sthree-bucket",
+            "bucket-s3alias",
+            "bucket--ol-s3",
+        ] {
+            assert!(validate_bucket_name(bucket).is_err(), "{bucket}");
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,263 @@
+use {
+    crate::{Arn, ArnRef, ScratchstackRelaxed, ValidationProfile},
+    alloc::{borrow::Cow, string::String},
+    core::ops::Range,
+};
+
+/// Characters that end an ARN in free text. Resources may legally contain some of these, but in logs and documents
+/// they far more often delimit the ARN.
+const TERMINATORS: &[char] = &['"', '\'', '`', ',', '<', '>', '(', ')', '[', ']', '{', '}', '|', '\\'];
+
+/// Punctuation that is dropped from the end of a candidate that is not a valid ARN, since it usually ends the
+/// surrounding sentence.
+const TRAILING_PUNCTUATION: &[char] = &['.', ':', ';', '!', '?'];
+
+/// An ARN found in text, along with its location.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ArnMatch {
+    arn: Arn,
+    start: usize,
+    end: usize,
+}
+
+impl ArnMatch {
+    /// Retrieve the ARN.
+    #[inline]
+    pub fn arn(&self) -> &Arn {
+        &self.arn
+    }
+
+    /// Unwrap the ARN.
+    #[inline]
+    pub fn into_arn(self) -> Arn {
+        self.arn
+    }
+
+    /// Retrieve the byte offset in the text where the ARN starts.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Retrieve the byte offset in the text just past the end of the ARN.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Retrieve the byte range of the ARN in the text.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// An iterator over the ARNs in a string. This is created by [find] and [find_with_profile].
+#[derive(Clone, Debug)]
+pub struct ArnMatches<'a, P: ValidationProfile + ?Sized = ScratchstackRelaxed> {
+    text: &'a str,
+    pos: usize,
+    profile: &'a P,
+}
+
+impl<P: ValidationProfile + ?Sized> Iterator for ArnMatches<'_, P> {
+    type Item = ArnMatch;
+
+    fn next(&mut self) -> Option<ArnMatch> {
+        while let Some(offset) = self.text[self.pos..].find("arn:") {
+            let start = self.pos + offset;
+            self.pos = start + 4;
+
+            // Require a word boundary before the scheme so `yarn:` and `my_arn:` are not scanned.
+            if self.text[..start].chars().next_back().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+
+            let candidate = &self.text[start..];
+            let len = candidate.find(|c: char| c.is_whitespace() || c.is_control() || TERMINATORS.contains(&c));
+            let candidate = &candidate[..len.unwrap_or(candidate.len())];
+
+            // Resources may end in punctuation (e.g. `bucket/report.`), so only trim it if the ARN is invalid with it.
+            let trimmed = candidate.trim_end_matches(TRAILING_PUNCTUATION);
+            let attempts = if trimmed.len() < candidate.len() {
+                &[candidate, trimmed][..]
+            } else {
+                &[candidate][..]
+            };
+            let parsed = attempts
+                .iter()
+                .find_map(|&candidate| Some((ArnRef::parse_with_profile(candidate, self.profile).ok()?, candidate)));
+
+            if let Some((arn, candidate)) = parsed {
+                let end = start + candidate.len();
+                self.pos = end;
+                return Some(ArnMatch {
+                    arn: arn.to_owned(),
+                    start,
+                    end,
+                });
+            }
+        }
+
+        self.pos = self.text.len();
+        None
+    }
+}
+
+/// Find the syntactically valid ARNs in `text`, such as a log line, stack trace, or JSON document, using the
+/// [ScratchstackRelaxed] rules.
+///
+/// Each component is validated as by [Arn::from_str](core::str::FromStr::from_str); candidates that fail are skipped.
+/// An ARN must start at a word boundary. Since resources may contain almost any character, an ARN is taken to end at
+/// the first whitespace, control character, quote, backtick, comma, bracket, `<`, `>`, `|`, or `\`. If the result is
+/// not a valid ARN, trailing `.`, `:`, `;`, `!`, and `?` are dropped as sentence punctuation and it is tried again;
+/// valid ARNs keep their trailing punctuation, so `arn:aws:s3:::bucket/report.` is found as written. For example, in
+/// `roles=["arn:aws:iam::123456789012:role/a","arn:aws:iam::123456789012:role/b"].` both roles are found without their
+/// quotes, brackets, or the final period.
+pub fn find(text: &str) -> ArnMatches<'_> {
+    find_with_profile(text, &ScratchstackRelaxed)
+}
+
+/// Find the syntactically valid ARNs in `text`, validating the components according to the specified
+/// [ValidationProfile].
+///
+/// The rules for where ARNs start and end are the same as for [find].
+pub fn find_with_profile<'a, P: ValidationProfile + ?Sized>(text: &'a str, profile: &'a P) -> ArnMatches<'a, P> {
+    ArnMatches {
+        text,
+        pos: 0,
+        profile,
+    }
+}
+
+/// Replace each ARN in `text` with the result of calling `f` on it. ARNs are found as by [find].
+///
+/// If `text` contains no ARNs, it is returned without copying.
+pub fn replace<F>(text: &str, f: F) -> Cow<'_, str>
+where
+    F: FnMut(&ArnMatch) -> String,
+{
+    replace_with_profile(text, &ScratchstackRelaxed, f)
+}
+
+/// Replace each ARN in `text` with the result of calling `f` on it, validating the components according to the
+/// specified [ValidationProfile]. ARNs are found as by [find_with_profile].
+///
+/// If `text` contains no ARNs, it is returned without copying.
+pub fn replace_with_profile<'a, P, F>(text: &'a str, profile: &P, mut f: F) -> Cow<'a, str>
+where
+    P: ValidationProfile + ?Sized,
+    F: FnMut(&ArnMatch) -> String,
+{
+    let mut result = String::new();
+    let mut last = 0;
+
+    for m in find_with_profile(text, profile) {
+        result.push_str(&text[last..m.start]);
+        result.push_str(&f(&m));
+        last = m.end;
+    }
+
+    if last == 0 {
+        return Cow::Borrowed(text);
+    }
+
+    result.push_str(&text[last..]);
+    Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{find, find_with_profile, replace},
+        crate::{Arn, AwsStrict},
+        pretty_assertions::assert_eq,
+        std::{borrow::Cow, str::FromStr},
+    };
+
+    /// Find the ARNs in `text`, returning the matched text of each.
+    fn found(text: &str) -> Vec<&str> {
+        find(text)
+            .map(|m| {
+                assert_eq!(m.arn().to_string(), &text[m.span()]);
+                &text[m.span()]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn check_delimiters() {
+        let text = r#"roles=["arn:aws:iam::123456789012:role/a","arn:aws:iam::123456789012:role/b"]."#;
+        assert_eq!(found(text), vec!["arn:aws:iam::123456789012:role/a", "arn:aws:iam::123456789012:role/b"]);
+
+        let text = "Access denied for arn:aws:s3:::bucket/key (retrying arn:aws:s3:::other), then 'arn:aws:s3:::x';";
+        assert_eq!(found(text), vec!["arn:aws:s3:::bucket/key", "arn:aws:s3:::other", "arn:aws:s3:::x"]);
+
+        // Trailing punctuation is kept if the ARN is valid with it, and dropped otherwise.
+        assert_eq!(found("Uploaded arn:aws:s3:::bucket/report. Done."), vec!["arn:aws:s3:::bucket/report."]);
+        let arn = format!("arn:aws:s3:::{}", "b".repeat(2048 - 13));
+        let text = format!("Uploaded {arn}. Done.");
+        assert_eq!(found(&text), vec![arn.as_str()]);
+        let text = format!("Uploaded {arn}.x");
+        assert_eq!(found(&text), Vec::<&str>::new());
+
+        let text = concat!(
+            "{\"resource\":\"arn:aws:lambda:us-east-1:123456789012:function:f:1\"}\n",
+            "<arn:aws:sns:us-east-1:123456789012:t>",
+        );
+        assert_eq!(
+            found(text),
+            vec!["arn:aws:lambda:us-east-1:123456789012:function:f:1", "arn:aws:sns:us-east-1:123456789012:t"]
+        );
+
+        // Escaped JSON inside JSON, as in CloudTrail request parameters.
+        let text = r#"{"policy":"{\"Resource\":\"arn:aws:s3:::bucket/*\"}"}"#;
+        assert_eq!(found(text), vec!["arn:aws:s3:::bucket/*"]);
+
+        let text = "`arn:aws:sqs:us-west-2:123456789012:queue`|arn:aws:sqs:us-west-2:123456789012:queue2\tend";
+        assert_eq!(
+            found(text),
+            vec!["arn:aws:sqs:us-west-2:123456789012:queue", "arn:aws:sqs:us-west-2:123456789012:queue2"]
+        );
+    }
+
+    #[test]
+    fn check_invalid_candidates() {
+        // Not at a word boundary, malformed, or with an empty resource.
+        assert_eq!(found("yarn:aws:s3:::bucket my_arn:aws:s3:::bucket"), Vec::<&str>::new());
+        assert_eq!(found("arn:aws arn: arn:aws:s3::: arn:AWS:s3:::bucket ARN:aws:s3:::bucket"), Vec::<&str>::new());
+
+        // A failed candidate does not hide a later ARN.
+        assert_eq!(found("arn:arn:aws:s3:::bucket"), vec!["arn:aws:s3:::bucket"]);
+        assert_eq!(found("arn:aws:s3:::"), Vec::<&str>::new());
+
+        // Spans are byte offsets.
+        let text = "ünïcödé arn:aws:s3:::bücket";
+        let m = find(text).next().unwrap();
+        assert_eq!(m.start(), text.find("arn").unwrap());
+        assert_eq!(m.end(), text.len());
+        assert_eq!(m.into_arn(), Arn::from_str("arn:aws:s3:::bücket").unwrap());
+    }
+
+    #[test]
+    fn check_profile() {
+        let text = "arn:aws:ec2:local:123456789012:instance/i-1 arn:aws:ec2:us-east-1:123456789012:instance/i-1";
+        assert_eq!(find(text).count(), 2);
+        let arns: Vec<_> = find_with_profile(text, &AwsStrict).map(|m| m.arn().region().to_string()).collect();
+        assert_eq!(arns, vec!["us-east-1"]);
+    }
+
+    #[test]
+    fn check_replace() {
+        let text = "user arn:aws:iam::123456789012:user/alice assumed \"arn:aws:iam::123456789012:role/admin\".";
+        let replaced = replace(text, |m| format!("<{}>", m.arn().resource()));
+        assert_eq!(replaced, "user <user/alice> assumed \"<role/admin>\".");
+
+        let text = "nothing to see here";
+        assert!(matches!(replace(text, |_| unreachable!()), Cow::Borrowed("nothing to see here")));
+
+        let replaced = replace("arn:aws:s3:::a", |m| m.arn().with_resource("b").unwrap().to_string());
+        assert_eq!(replaced, "arn:aws:s3:::b");
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,63 @@
+use {
+    crate::Arn,
+    rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef},
+    std::str::FromStr,
+};
+
+/// Reads an ARN stored as text. Invalid ARNs are returned as [FromSqlError::Other] wrapping the
+/// [ArnError](crate::ArnError).
+impl FromSql for Arn {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let s = value.as_str()?;
+        Self::from_str(s).map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+/// Stores an ARN as text.
+impl ToSql for Arn {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.as_arn_ref().as_str().as_bytes())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::{Arn, ArnError},
+        pretty_assertions::assert_eq,
+        rusqlite::{Connection, Error as SqlError},
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE resources (arn TEXT NOT NULL)", ()).unwrap();
+
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        conn.execute("INSERT INTO resources (arn) VALUES (?1)", (&arn,)).unwrap();
+
+        let stored: String = conn.query_row("SELECT arn FROM resources", (), |row| row.get(0)).unwrap();
+        assert_eq!(stored, "arn:aws:ec2:us-east-1:123456789012:instance/i-1");
+
+        let loaded: Arn = conn.query_row("SELECT arn FROM resources", (), |row| row.get(0)).unwrap();
+        assert_eq!(loaded, arn);
+    }
+
+    #[test]
+    fn check_invalid() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        let err = conn.query_row("SELECT 'arn:aws:ec2'", (), |row| row.get::<_, Arn>(0)).unwrap_err();
+        match err {
+            SqlError::FromSqlConversionFailure(0, _, e) => {
+                assert_eq!(e.downcast_ref::<ArnError>().unwrap(), &ArnError::InvalidArn("arn:aws:ec2".into()));
+            }
+            _ => panic!("Unexpected error: {err:?}"),
+        }
+
+        let err = conn.query_row("SELECT 12", (), |row| row.get::<_, Arn>(0)).unwrap_err();
+        assert!(matches!(err, SqlError::InvalidColumnType(..)), "{err:?}");
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,122 @@
+use {
+    crate::Arn,
+    proptest::{
+        arbitrary::Arbitrary,
+        prop_oneof,
+        strategy::{BoxedStrategy, Just, Strategy},
+        string::string_regex,
+    },
+};
+
+/// Lowercase letters, including non-ASCII letters and letters from scripts without case, as a regex class body.
+const LETTERS: &str = "a-zéçøòệắœ中国日本東京";
+
+/// A strategy for strings matching a regex known to be valid.
+fn regex(pattern: &str) -> impl Strategy<Value = String> {
+    string_regex(pattern).expect("invalid regex")
+}
+
+/// Generate partitions accepted by [validate_partition](crate::utils::validate_partition), e.g. `aws`, `aws-cn`,
+/// or `aws-中国`.
+pub fn partition() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("aws".to_string()),
+        Just("aws-cn".to_string()),
+        Just("aws-us-gov".to_string()),
+        regex(&format!("[{LETTERS}0-9]{{1,4}}(-[{LETTERS}0-9]{{1,4}})?")),
+    ]
+}
+
+/// Generate services accepted by [validate_service](crate::utils::validate_service), e.g. `ec2` or `één`.
+pub fn service() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("ec2".to_string()),
+        Just("iam".to_string()),
+        Just("s3".to_string()),
+        regex(&format!("[{LETTERS}0-9]{{1,12}}(-[{LETTERS}0-9]{{1,12}})?")),
+    ]
+}
+
+/// Generate non-empty regions accepted by [validate_region](crate::utils::validate_region), including `local`,
+/// non-ASCII regions such as `日本-東京-1`, and regions with a local region appended such as `us-east-1-bos-1`.
+pub fn region() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("local".to_string()),
+        Just("us-east-1".to_string()),
+        regex(&format!(
+            "[{LETTERS}]{{1,8}}(-[{LETTERS}]{{1,8}}){{0,2}}-[0-9]{{1,2}}(-[{LETTERS}]{{1,5}}-[0-9]{{1,2}})?"
+        )),
+    ]
+}
+
+/// Generate non-empty account ids accepted by [validate_account_id](crate::utils::validate_account_id): 12 digits or
+/// `aws`.
+pub fn account_id() -> impl Strategy<Value = String> {
+    prop_oneof![Just("aws".to_string()), regex("[0-9]{12}")]
+}
+
+/// Generate resources accepted by [validate_resource](crate::utils::validate_resource), e.g. `bucket/key` or
+/// `function:name:1`.
+pub fn resource() -> impl Strategy<Value = String> {
+    regex(&format!("[{LETTERS}A-Z0-9/:_.*?+=@ -]{{1,64}}"))
+}
+
+/// Generate valid ARNs. Regions and account ids may be empty.
+pub fn arn() -> impl Strategy<Value = Arn> {
+    (
+        partition(),
+        service(),
+        prop_oneof![1 => Just(String::new()), 3 => region()],
+        prop_oneof![1 => Just(String::new()), 3 => account_id()],
+        resource(),
+    )
+        .prop_map(|(partition, service, region, account_id, resource)| {
+            Arn::new(&partition, &service, &region, &account_id, &resource).expect("strategy produced an invalid ARN")
+        })
+}
+
+/// Allows `any::<Arn>()` to be used; this is the same as [arn].
+impl Arbitrary for Arn {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Arn>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        arn().boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::{account_id, partition, region, resource, service},
+        crate::{
+            utils::{validate_account_id, validate_partition, validate_region, validate_resource, validate_service},
+            Arn,
+        },
+        proptest::{prelude::any, proptest},
+        std::str::FromStr,
+    };
+
+    proptest! {
+        #[test]
+        fn check_components(
+            partition in partition(),
+            service in service(),
+            region in region(),
+            account_id in account_id(),
+            resource in resource(),
+        ) {
+            validate_partition(&partition).unwrap();
+            validate_service(&service).unwrap();
+            validate_region(&region).unwrap();
+            validate_account_id(&account_id).unwrap();
+            validate_resource(&resource).unwrap();
+        }
+
+        #[test]
+        fn check_arn(arn in any::<Arn>()) {
+            assert_eq!(Arn::from_str(&arn.to_string()).unwrap(), arn);
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,144 @@
+use {
+    crate::Arn,
+    alloc::string::String,
+    core::{
+        fmt::{Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+    serde::{
+        de::{self, value::MapAccessDeserializer, MapAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+};
+
+/// The components of an ARN as written to a map.
+#[derive(Serialize)]
+struct ComponentsRef<'a> {
+    partition: &'a str,
+    service: &'a str,
+    region: &'a str,
+    account_id: &'a str,
+    resource: &'a str,
+}
+
+/// The components of an ARN as read from a map. The region and account id may be omitted if they are empty.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Components {
+    partition: String,
+    service: String,
+    #[serde(default)]
+    region: String,
+    #[serde(default)]
+    account_id: String,
+    resource: String,
+}
+
+/// Serialize an [Arn] as a map with the keys `partition`, `service`, `region`, `account_id`, and `resource`.
+pub fn serialize<S>(arn: &Arn, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ComponentsRef {
+        partition: arn.partition(),
+        service: arn.service(),
+        region: arn.region(),
+        account_id: arn.account_id(),
+        resource: arn.resource(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize an [Arn] from either a map written by [serialize] or the string form.
+///
+/// In the map form, `region` and `account_id` may be omitted if they are empty. Since the form is detected from the
+/// input, this requires a self-describing format such as JSON.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Arn, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(ArnVisitor)
+}
+
+struct ArnVisitor;
+
+impl<'de> Visitor<'de> for ArnVisitor {
+    type Value = Arn;
+
+    fn expecting(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("an ARN string or a map of ARN components")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Arn, E> {
+        Arn::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Arn, A::Error> {
+        let c = Components::deserialize(MapAccessDeserializer::new(map))?;
+        Arn::new(&c.partition, &c.service, &c.region, &c.account_id, &c.resource).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::Arn,
+        pretty_assertions::assert_eq,
+        serde::{Deserialize, Serialize},
+        std::str::FromStr,
+    };
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Record {
+        #[serde(with = "crate::structured")]
+        arn: Arn,
+    }
+
+    #[test]
+    fn check_round_trip() {
+        let record = Record {
+            arn: Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"arn":{"partition":"aws","service":"ec2","region":"us-east-1","account_id":"123456789012","resource":"instance/i-1"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+        // The string form is also accepted.
+        let record2: Record =
+            serde_json::from_str(r#"{"arn":"arn:aws:ec2:us-east-1:123456789012:instance/i-1"}"#).unwrap();
+        assert_eq!(record2, record);
+
+        // The default representation is unchanged.
+        assert_eq!(serde_json::to_string(&record.arn).unwrap(), r#""arn:aws:ec2:us-east-1:123456789012:instance/i-1""#);
+    }
+
+    #[test]
+    fn check_map_form() {
+        let record: Record =
+            serde_json::from_str(r#"{"arn":{"partition":"aws","service":"s3","resource":"bucket"}}"#).unwrap();
+        assert_eq!(record.arn, Arn::from_str("arn:aws:s3:::bucket").unwrap());
+
+        let err = serde_json::from_str::<Record>(r#"{"arn":{"partition":"Aws","service":"s3","resource":"bucket"}}"#)
+            .unwrap_err();
+        assert!(err.to_string().starts_with(r#"Invalid partition: "Aws""#), "{err}");
+
+        let err = serde_json::from_str::<Record>(r#"{"arn":{"partition":"aws","service":"s3"}}"#).unwrap_err();
+        assert!(err.to_string().contains("missing field `resource`"), "{err}");
+
+        let err = serde_json::from_str::<Record>(
+            r#"{"arn":{"partition":"aws","service":"s3","resource":"bucket","bucket":"x"}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown field `bucket`"), "{err}");
+
+        let err = serde_json::from_str::<Record>(r#"{"arn":"arn:aws:s3"}"#).unwrap_err();
+        assert!(err.to_string().starts_with(r#"Invalid ARN: "arn:aws:s3""#), "{err}");
+
+        let err = serde_json::from_str::<Record>(r#"{"arn":12}"#).unwrap_err();
+        assert!(err.to_string().contains("an ARN string or a map of ARN components"), "{err}");
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,463 @@
+use {
+    crate::{
+        utils::{validate_partition, validate_region},
+        Arn, ArnError,
+    },
+    alloc::{
+        collections::BTreeMap,
+        format,
+        string::{String, ToString},
+    },
+    core::{
+        error::Error,
+        fmt::{Display, Formatter, Result as FmtResult},
+    },
+    serde::Deserialize,
+};
+
+/// A declarative mapping of ARNs and service principals from one partition to another.
+///
+/// Each region in the source partition that has a counterpart in the target partition must be listed explicitly;
+/// translating an ARN in any other region fails with [TranslationError::UnmappedRegion]. If the partitions use
+/// different DNS suffixes (e.g. `amazonaws.com` and `amazonaws.com.cn`), these are set with
+/// [PartitionTranslation::with_dns_suffixes] so service principals can be translated.
+///
+/// A translation can also be deserialized from a table in the form:
+///
+/// ```json
+/// {
+///     "from": "aws",
+///     "to": "aws-cn",
+///     "regions": { "us-east-1": "cn-north-1", "us-west-2": "cn-northwest-1" },
+///     "dns_suffixes": { "from": "amazonaws.com", "to": "amazonaws.com.cn" }
+/// }
+/// ```
+///
+/// The `regions` and `dns_suffixes` keys are optional. The table is validated as by [PartitionTranslation::new],
+/// [PartitionTranslation::add_region], and [PartitionTranslation::with_dns_suffixes].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(try_from = "TranslationTable")]
+pub struct PartitionTranslation {
+    from_partition: String,
+    to_partition: String,
+    regions: BTreeMap<String, String>,
+    dns_suffixes: Option<(String, String)>,
+}
+
+impl PartitionTranslation {
+    /// Create a new translation from the partition `from` to the partition `to` with no regions mapped.
+    ///
+    /// # Errors
+    ///
+    /// If either partition does not meet the rules specified in [validate_partition], [TranslationError::Arn]
+    /// wrapping [ArnError::InvalidPartition] is returned.
+    pub fn new(from: &str, to: &str) -> Result<Self, TranslationError> {
+        validate_partition(from)?;
+        validate_partition(to)?;
+
+        Ok(Self {
+            from_partition: from.to_string(),
+            to_partition: to.to_string(),
+            regions: BTreeMap::new(),
+            dns_suffixes: None,
+        })
+    }
+
+    /// Map the region `from` in the source partition to the region `to` in the target partition, replacing any
+    /// existing mapping for `from`.
+    ///
+    /// # Errors
+    ///
+    /// If either region does not meet the rules specified in [validate_region], [TranslationError::Arn] wrapping
+    /// [ArnError::InvalidRegion] is returned.
+    pub fn add_region(&mut self, from: &str, to: &str) -> Result<(), TranslationError> {
+        validate_region(from)?;
+        validate_region(to)?;
+        self.regions.insert(from.to_string(), to.to_string());
+        Ok(())
+    }
+
+    /// Set the DNS suffixes of service principals in the source and target partitions, such as `amazonaws.com` and
+    /// `amazonaws.com.cn`.
+    ///
+    /// # Errors
+    ///
+    /// If either suffix is not a valid DNS name, [TranslationError::InvalidDnsSuffix] is returned.
+    pub fn with_dns_suffixes(mut self, from: &str, to: &str) -> Result<Self, TranslationError> {
+        validate_dns_suffix(from)?;
+        validate_dns_suffix(to)?;
+        self.dns_suffixes = Some((from.to_string(), to.to_string()));
+        Ok(self)
+    }
+
+    /// Retrieve the source partition.
+    #[inline]
+    pub fn from_partition(&self) -> &str {
+        &self.from_partition
+    }
+
+    /// Retrieve the target partition.
+    #[inline]
+    pub fn to_partition(&self) -> &str {
+        &self.to_partition
+    }
+
+    /// Retrieve the region in the target partition that `region` maps to, if any.
+    #[inline]
+    pub fn region(&self, region: &str) -> Option<&str> {
+        self.regions.get(region).map(String::as_str)
+    }
+
+    /// Iterate over the region mappings as `(from, to)` pairs, sorted by the source region.
+    #[inline]
+    pub fn regions(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.regions.iter().map(|(from, to)| (from.as_str(), to.as_str()))
+    }
+
+    /// Retrieve the DNS suffixes of service principals in the source and target partitions, if set.
+    #[inline]
+    pub fn dns_suffixes(&self) -> Option<(&str, &str)> {
+        self.dns_suffixes.as_ref().map(|(from, to)| (from.as_str(), to.as_str()))
+    }
+
+    /// Translate `arn` to the target partition. This is equivalent to [Arn::translate].
+    ///
+    /// # Errors
+    ///
+    /// See [Arn::translate].
+    #[inline]
+    pub fn translate(&self, arn: &Arn) -> Result<Arn, TranslationError> {
+        arn.translate(self)
+    }
+
+    /// Translate a service principal, such as `lambda.amazonaws.com` or `logs.us-east-1.amazonaws.com`, to the
+    /// target partition.
+    ///
+    /// The DNS suffix is replaced if DNS suffixes have been set. If the label immediately before the suffix is a
+    /// region in the source partition, it is replaced by its counterpart in the target partition.
+    ///
+    /// # Errors
+    ///
+    /// * If DNS suffixes have been set and `principal` does not end with the source suffix,
+    ///   [TranslationError::UnmappedDnsSuffix] is returned.
+    /// * If the principal has a region that looks like it belongs to the source partition but has no counterpart in
+    ///   the target partition, [TranslationError::UnmappedRegion] is returned.
+    pub fn translate_service_principal(&self, principal: &str) -> Result<String, TranslationError> {
+        let (service, to_suffix) = match &self.dns_suffixes {
+            Some((from_suffix, to_suffix)) => {
+                let service = principal
+                    .strip_suffix(from_suffix.as_str())
+                    .and_then(|s| s.strip_suffix('.'))
+                    .filter(|s| !s.is_empty())
+                    .ok_or_else(|| TranslationError::UnmappedDnsSuffix(principal.to_string()))?;
+                (service, to_suffix.as_str())
+            }
+            None => {
+                // Without a suffix mapping, the suffix is taken to be everything after the first label or, for
+                // regional principals, after the region.
+                let Some((service, suffix)) = principal.split_once('.') else {
+                    return Ok(principal.to_string());
+                };
+
+                match suffix.split_once('.') {
+                    Some((region, rest)) if self.is_source_region(region) => {
+                        let region = self.map_region(region)?;
+                        return Ok(format!("{service}.{region}.{rest}"));
+                    }
+                    _ => return Ok(principal.to_string()),
+                }
+            }
+        };
+
+        match service.rsplit_once('.') {
+            Some((name, region)) if self.is_source_region(region) => {
+                let region = self.map_region(region)?;
+                Ok(format!("{name}.{region}.{to_suffix}"))
+            }
+            _ => Ok(format!("{service}.{to_suffix}")),
+        }
+    }
+
+    /// Indicates whether `region` should be translated: either it is mapped, or it has the same leading label as a
+    /// mapped region (so `us-east-3` is recognized when `us-east-1` is mapped).
+    fn is_source_region(&self, region: &str) -> bool {
+        if self.regions.contains_key(region) {
+            return true;
+        }
+
+        if validate_region(region).is_err() {
+            return false;
+        }
+
+        let Some((prefix, _)) = region.split_once('-') else {
+            return false;
+        };
+
+        self.regions.keys().any(|r| r.split_once('-').map(|(p, _)| p) == Some(prefix))
+    }
+
+    /// Map a region in the source partition to the target partition, failing if it has no counterpart.
+    pub(crate) fn map_region(&self, region: &str) -> Result<&str, TranslationError> {
+        self.region(region).ok_or_else(|| TranslationError::UnmappedRegion {
+            region: region.to_string(),
+            to_partition: self.to_partition.clone(),
+        })
+    }
+}
+
+/// The declarative form of a [PartitionTranslation].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TranslationTable {
+    from: String,
+    to: String,
+    #[serde(default)]
+    regions: BTreeMap<String, String>,
+    #[serde(default)]
+    dns_suffixes: Option<DnsSuffixes>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DnsSuffixes {
+    from: String,
+    to: String,
+}
+
+impl TryFrom<TranslationTable> for PartitionTranslation {
+    type Error = TranslationError;
+
+    fn try_from(table: TranslationTable) -> Result<Self, TranslationError> {
+        let mut translation = Self::new(&table.from, &table.to)?;
+        for (from, to) in &table.regions {
+            translation.add_region(from, to)?;
+        }
+
+        match table.dns_suffixes {
+            Some(suffixes) => translation.with_dns_suffixes(&suffixes.from, &suffixes.to),
+            None => Ok(translation),
+        }
+    }
+}
+
+/// Verify that `suffix` is a plausible DNS suffix: one or more non-empty labels of ASCII letters, digits, and `-`,
+/// separated by `.`.
+fn validate_dns_suffix(suffix: &str) -> Result<(), TranslationError> {
+    let valid = !suffix.is_empty()
+        && suffix.len() <= 253
+        && suffix.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(TranslationError::InvalidDnsSuffix(suffix.to_string()))
+    }
+}
+
+/// Errors that can be raised while translating ARNs between partitions.
+#[derive(Debug, Eq, PartialEq)]
+pub enum TranslationError {
+    /// A component of the translation table or of the translated ARN is invalid.
+    Arn(ArnError),
+
+    /// A DNS suffix in the translation table is invalid. The argument contains the specified suffix.
+    InvalidDnsSuffix(String),
+
+    /// The ARN is not in the source partition of the translation.
+    PartitionMismatch {
+        /// The source partition of the translation.
+        expected: String,
+
+        /// The partition of the ARN.
+        actual: String,
+    },
+
+    /// A service principal does not end with the source DNS suffix. The argument contains the specified principal.
+    UnmappedDnsSuffix(String),
+
+    /// A region has no counterpart in the target partition.
+    UnmappedRegion {
+        /// The region being translated.
+        region: String,
+
+        /// The target partition of the translation.
+        to_partition: String,
+    },
+}
+
+impl Error for TranslationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Arn(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TranslationError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Arn(e) => Display::fmt(e, f),
+            Self::InvalidDnsSuffix(suffix) => write!(f, "Invalid DNS suffix: {suffix:#?}"),
+            Self::PartitionMismatch {
+                expected,
+                actual,
+            } => write!(f, "ARN is in partition {actual:#?}, not {expected:#?}"),
+            Self::UnmappedDnsSuffix(principal) => {
+                write!(f, "Service principal {principal:#?} does not have the source DNS suffix")
+            }
+            Self::UnmappedRegion {
+                region,
+                to_partition,
+            } => write!(f, "Region {region:#?} has no counterpart in partition {to_partition:#?}"),
+        }
+    }
+}
+
+impl From<ArnError> for TranslationError {
+    fn from(err: ArnError) -> Self {
+        Self::Arn(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{PartitionTranslation, TranslationError},
+        crate::{Arn, ArnError},
+        pretty_assertions::assert_eq,
+        std::str::FromStr,
+    };
+
+    fn aws_to_cn() -> PartitionTranslation {
+        let mut t = PartitionTranslation::new("aws", "aws-cn").unwrap();
+        t.add_region("us-east-1", "cn-north-1").unwrap();
+        t.add_region("us-west-2", "cn-northwest-1").unwrap();
+        t.with_dns_suffixes("amazonaws.com", "amazonaws.com.cn").unwrap()
+    }
+
+    #[test]
+    fn check_translate_arn() {
+        let t = aws_to_cn();
+
+        let arn = Arn::from_str("arn:aws:ec2:us-east-1:123456789012:instance/i-1").unwrap();
+        assert_eq!(arn.translate(&t).unwrap().to_string(), "arn:aws-cn:ec2:cn-north-1:123456789012:instance/i-1");
+
+        let arn = Arn::from_str("arn:aws:s3:::bucket/key").unwrap();
+        assert_eq!(t.translate(&arn).unwrap().to_string(), "arn:aws-cn:s3:::bucket/key");
+
+        let arn = Arn::from_str("arn:aws:ec2:eu-west-1:123456789012:instance/i-1").unwrap();
+        let e = arn.translate(&t).unwrap_err();
+        assert_eq!(
+            e,
+            TranslationError::UnmappedRegion {
+                region: "eu-west-1".to_string(),
+                to_partition: "aws-cn".to_string(),
+            }
+        );
+        assert_eq!(e.to_string(), r#"Region "eu-west-1" has no counterpart in partition "aws-cn""#);
+
+        let arn = Arn::from_str("arn:aws-cn:s3:::bucket").unwrap();
+        let e = arn.translate(&t).unwrap_err();
+        assert_eq!(e.to_string(), r#"ARN is in partition "aws-cn", not "aws""#);
+    }
+
+    #[test]
+    fn check_translate_service_principal() {
+        let t = aws_to_cn();
+        assert_eq!(t.translate_service_principal("lambda.amazonaws.com").unwrap(), "lambda.amazonaws.com.cn");
+        assert_eq!(
+            t.translate_service_principal("logs.us-east-1.amazonaws.com").unwrap(),
+            "logs.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            t.translate_service_principal("logs.us-east-2.amazonaws.com").unwrap_err().to_string(),
+            r#"Region "us-east-2" has no counterpart in partition "aws-cn""#
+        );
+        assert_eq!(
+            t.translate_service_principal("lambda.example.com").unwrap_err(),
+            TranslationError::UnmappedDnsSuffix("lambda.example.com".to_string())
+        );
+        assert_eq!(
+            t.translate_service_principal("amazonaws.com").unwrap_err(),
+            TranslationError::UnmappedDnsSuffix("amazonaws.com".to_string())
+        );
+
+        // Partitions sharing a DNS suffix need only the regions mapped.
+        let mut t = PartitionTranslation::new("aws", "aws-us-gov").unwrap();
+        t.add_region("us-west-2", "us-gov-west-1").unwrap();
+        assert_eq!(t.translate_service_principal("lambda.amazonaws.com").unwrap(), "lambda.amazonaws.com");
+        assert_eq!(
+            t.translate_service_principal("logs.us-west-2.amazonaws.com").unwrap(),
+            "logs.us-gov-west-1.amazonaws.com"
+        );
+        assert_eq!(t.translate_service_principal("localhost").unwrap(), "localhost");
+    }
+
+    #[test]
+    fn check_table() {
+        let t: PartitionTranslation = serde_json::from_str(
+            r#"{
+                "from": "aws",
+                "to": "aws-cn",
+                "regions": {"us-east-1": "cn-north-1", "us-west-2": "cn-northwest-1"},
+                "dns_suffixes": {"from": "amazonaws.com", "to": "amazonaws.com.cn"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(t, aws_to_cn());
+        assert_eq!(t.from_partition(), "aws");
+        assert_eq!(t.to_partition(), "aws-cn");
+        assert_eq!(t.region("us-west-2"), Some("cn-northwest-1"));
+        assert_eq!(t.region("us-west-1"), None);
+        assert_eq!(t.regions().collect::<Vec<_>>(), vec![("us-east-1", "cn-north-1"), ("us-west-2", "cn-northwest-1")]);
+        assert_eq!(t.dns_suffixes(), Some(("amazonaws.com", "amazonaws.com.cn")));
+
+        let t: PartitionTranslation = serde_json::from_str(r#"{"from": "aws", "to": "aws-iso"}"#).unwrap();
+        assert_eq!(t.regions().count(), 0);
+        assert_eq!(t.dns_suffixes(), None);
+
+        let e = serde_json::from_str::<PartitionTranslation>(
+            r#"{"from": "aws", "to": "aws-cn", "regions": {"us-east-1": "cn_north_1"}}"#,
+        )
+        .unwrap_err();
+        assert!(e.to_string().contains("Invalid region: \"cn_north_1\""), "{e}");
+        let extra = r#"{"from": "aws", "to": "aws-cn", "extra": 1}"#;
+        assert!(serde_json::from_str::<PartitionTranslation>(extra).is_err());
+        assert!(serde_json::from_str::<PartitionTranslation>(r#"{"from": "aws"}"#).is_err());
+    }
+
+    #[test]
+    fn check_invalid_table() {
+        assert_eq!(
+            PartitionTranslation::new("aws", "AWS").unwrap_err(),
+            TranslationError::Arn(ArnError::InvalidPartition("AWS".to_string()))
+        );
+        let mut t = PartitionTranslation::new("aws", "aws-cn").unwrap();
+        assert_eq!(
+            t.add_region("", "cn-north-1").unwrap_err(),
+            TranslationError::Arn(ArnError::InvalidRegion("".to_string()))
+        );
+        for suffix in ["", "amazonaws..com", "-amazonaws.com", "amazon_aws.com"] {
+            let e = t.clone().with_dns_suffixes("amazonaws.com", suffix).unwrap_err();
+            assert_eq!(e, TranslationError::InvalidDnsSuffix(suffix.to_string()));
+        }
+        assert_eq!(TranslationError::InvalidDnsSuffix("x_y".to_string()).to_string(), r#"Invalid DNS suffix: "x_y""#);
+        assert_eq!(
+            TranslationError::UnmappedDnsSuffix("x.y".to_string()).to_string(),
+            r#"Service principal "x.y" does not have the source DNS suffix"#
+        );
+
+        let e = TranslationError::from(ArnError::InvalidRegion("x".to_string()));
+        assert_eq!(e.to_string(), r#"Invalid region: "x""#);
+        assert!(std::error::Error::source(&e).is_some());
+        assert!(std::error::Error::source(&TranslationError::InvalidDnsSuffix("".to_string())).is_none());
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,86 @@
+use {
+    crate::ArnError,
+    alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    },
+};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Percent-encode `value` for use as a single URL path segment.
+///
+/// This follows the label encoding used by AWS REST-JSON services: every byte other than the RFC 3986 unreserved
+/// characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, and `~`) is encoded as `%XX` with uppercase hex digits. In
+/// particular, `:` is encoded as `%3A` and `/` as `%2F`.
+pub(crate) fn encode_segment(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for b in value.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            result.push(b as char);
+        } else {
+            result.push('%');
+            result.push(HEX_DIGITS[(b >> 4) as usize] as char);
+            result.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+        }
+    }
+
+    result
+}
+
+/// Decode a percent-encoded URL path segment. Hex digits may be uppercase or lowercase, and characters that did not
+/// need to be encoded are accepted as-is.
+///
+/// If the segment contains a malformed escape or does not decode to UTF-8, [ArnError::InvalidArn] is returned.
+pub(crate) fn decode_segment(segment: &str) -> Result<String, ArnError> {
+    let invalid = || ArnError::InvalidArn(segment.to_string());
+    let bytes = segment.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let digit = |j: usize| bytes.get(j).and_then(|&b| (b as char).to_digit(16));
+            let (Some(high), Some(low)) = (digit(i + 1), digit(i + 2)) else {
+                return Err(invalid());
+            };
+            result.push((high * 16 + low) as u8);
+            i += 3;
+        } else {
+            result.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(result).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{decode_segment, encode_segment},
+        crate::ArnError,
+        pretty_assertions::assert_eq,
+    };
+
+    #[test]
+    fn check_encode() {
+        assert_eq!(encode_segment("arn:aws:s3:::bucket/key"), "arn%3Aaws%3As3%3A%3A%3Abucket%2Fkey");
+        assert_eq!(encode_segment("a-b.c_d~e"), "a-b.c_d~e");
+        assert_eq!(encode_segment("a b+c%d?e#f"), "a%20b%2Bc%25d%3Fe%23f");
+        assert_eq!(encode_segment("日本"), "%E6%97%A5%E6%9C%AC");
+    }
+
+    #[test]
+    fn check_decode() {
+        assert_eq!(decode_segment("arn%3Aaws%3as3%3A%3A%3Abucket%2Fkey").unwrap(), "arn:aws:s3:::bucket/key");
+        assert_eq!(decode_segment("arn:aws:s3:::a+b").unwrap(), "arn:aws:s3:::a+b");
+        assert_eq!(decode_segment("%E6%97%A5%E6%9C%AC").unwrap(), "日本");
+
+        for bad in ["abc%", "abc%4", "abc%4G", "abc%+1", "%FF", "%E6%97"] {
+            assert_eq!(decode_segment(bad).unwrap_err(), ArnError::InvalidArn(bad.to_string()));
+        }
+    }
+}
+// end tests -- do not delete; needed for coverage.
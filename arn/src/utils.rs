@@ -1,4 +1,80 @@
-use crate::ArnError;
+use {
+    crate::{ArnError, ArnErrorReason},
+    alloc::{borrow::Cow, string::ToString},
+    core::ops::Range,
+    unicode_normalization::{is_nfkc, UnicodeNormalization},
+};
+
+/// The reason a component failed validation, and the byte range of the offending characters within the component.
+#[derive(Debug)]
+pub(crate) struct Diagnosis {
+    pub(crate) reason: ArnErrorReason,
+    pub(crate) span: Range<usize>,
+}
+
+impl Diagnosis {
+    fn new(reason: ArnErrorReason, span: Range<usize>) -> Self {
+        Self {
+            reason,
+            span,
+        }
+    }
+
+    fn at(reason: ArnErrorReason, pos: usize, c: char) -> Self {
+        Self::new(reason, pos..pos + c.len_utf8())
+    }
+}
+
+/// Check a non-empty component composed of characters accepted by `allowed`, separated by single `-` characters.
+///
+/// If `max_len` is specified, the component may not have a character starting at or beyond this byte offset.
+fn check_dashed<F: Fn(char) -> bool>(value: &str, allowed: F, max_len: Option<usize>) -> Result<(), Diagnosis> {
+    let mut last_was_dash = true;
+
+    for (i, c) in value.char_indices() {
+        if let Some(max_len) = max_len {
+            if i == max_len {
+                return Err(Diagnosis::new(ArnErrorReason::TooLong(max_len), i..value.len()));
+            }
+        }
+
+        if allowed(c) {
+            last_was_dash = false;
+        } else if c == '-' {
+            if last_was_dash {
+                let reason = if i == 0 {
+                    ArnErrorReason::LeadingDash
+                } else {
+                    ArnErrorReason::ConsecutiveDashes
+                };
+                return Err(Diagnosis::at(reason, i, c));
+            }
+
+            last_was_dash = true;
+        } else {
+            return Err(Diagnosis::at(ArnErrorReason::InvalidCharacter(c), i, c));
+        }
+    }
+
+    if last_was_dash {
+        Err(Diagnosis::new(ArnErrorReason::TrailingDash, value.len() - 1..value.len()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Convert an ARN component to NFKC normalized form.
+///
+/// Input typed on some systems (notably macOS) uses decomposed characters, e.g. `c` followed by a combining cedilla
+/// instead of `ç`. These look identical but fail validation and compare unequal. If `value` is already normalized, it
+/// is returned without copying.
+pub fn normalize(value: &str) -> Cow<'_, str> {
+    if is_nfkc(value) {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(value.nfkc().collect())
+    }
+}
 
 /// Verify that a partition name meets the naming requirements.
 ///
@@ -13,8 +89,9 @@ use crate::ArnError;
 /// uppercase and lowercase.
 ///
 /// The value must be in NFKC normalized form for validation on accented characters to succeed. For example, `ç`
-/// represented as the codepoint `\u{0231}` ("Latin small letter c with cedilla") is valid, but `\u{0063}\u{0327}`
-/// ("Latin small letter c" followed by "combining cedilla") is not.
+/// represented as the codepoint `\u{00e7}` ("Latin small letter c with cedilla") is valid, but `\u{0063}\u{0327}`
+/// ("Latin small letter c" followed by "combining cedilla") is not. Use [normalize] (or
+/// [Arn::new_normalized](crate::Arn::new_normalized)) to convert input to this form first.
 ///
 /// Examples of valid partition names:
 ///
@@ -27,34 +104,16 @@ use crate::ArnError;
 ///
 /// If `partition` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidPartition] error is returned.
 pub fn validate_partition(partition: &str) -> Result<(), ArnError> {
-    if partition.is_empty() {
-        return Err(ArnError::InvalidPartition(partition.to_string()));
-    }
-
-    let mut last_was_dash = true;
-    for (i, c) in partition.char_indices() {
-        if i == 32 {
-            return Err(ArnError::InvalidPartition(partition.to_string()));
-        }
-
-        if (c.is_alphabetic() && !c.is_uppercase()) || c.is_ascii_digit() {
-            last_was_dash = false;
-        } else if c == '-' {
-            if last_was_dash {
-                return Err(ArnError::InvalidPartition(partition.to_string()));
-            }
+    check_partition(partition).map_err(|_| ArnError::InvalidPartition(partition.to_string()))
+}
 
-            last_was_dash = true;
-        } else {
-            return Err(ArnError::InvalidPartition(partition.to_string()));
-        }
+/// Check a partition against the rules of [validate_partition], diagnosing any failure.
+pub(crate) fn check_partition(partition: &str) -> Result<(), Diagnosis> {
+    if partition.is_empty() {
+        return Err(Diagnosis::new(ArnErrorReason::Empty, 0..0));
     }
 
-    if last_was_dash {
-        Err(ArnError::InvalidPartition(partition.to_string()))
-    } else {
-        Ok(())
-    }
+    check_dashed(partition, |c| (c.is_alphabetic() && !c.is_uppercase()) || c.is_ascii_digit(), Some(32))
 }
 
 /// Verify that an account id meets AWS requirements.
@@ -64,20 +123,40 @@ pub fn validate_partition(partition: &str) -> Result<(), ArnError> {
 /// If `account_id` meets this requirement, Ok is returned. Otherwise, a [ArnError::InvalidAccountId] error is
 /// returned.
 pub fn validate_account_id(account_id: &str) -> Result<(), ArnError> {
-    if account_id != "aws" {
-        let a_bytes = account_id.as_bytes();
+    validate_account_id_digits(account_id, 12)
+}
 
-        if a_bytes.len() != 12 {
-            return Err(ArnError::InvalidAccountId(account_id.to_string()));
-        }
+/// Verify that an account id is composed of exactly `digits` ASCII digits or is the string `aws`.
+///
+/// This is useful for [ValidationProfile](crate::ValidationProfile) implementations for deployments that use account
+/// ids of a length other than the 12 digits used by AWS.
+///
+/// If `account_id` meets this requirement, Ok is returned. Otherwise, a [ArnError::InvalidAccountId] error is
+/// returned.
+pub fn validate_account_id_digits(account_id: &str, digits: usize) -> Result<(), ArnError> {
+    check_account_id_digits(account_id, digits).map_err(|_| ArnError::InvalidAccountId(account_id.to_string()))
+}
 
-        for c in a_bytes.iter() {
-            if !c.is_ascii_digit() {
-                return Err(ArnError::InvalidAccountId(account_id.to_string()));
-            }
+/// Check an account id against the rules of [validate_account_id_digits], diagnosing any failure.
+pub(crate) fn check_account_id_digits(account_id: &str, digits: usize) -> Result<(), Diagnosis> {
+    if account_id == "aws" {
+        return Ok(());
+    }
+
+    if account_id.is_empty() {
+        return Err(Diagnosis::new(ArnErrorReason::Empty, 0..0));
+    }
+
+    for (i, c) in account_id.char_indices() {
+        if !c.is_ascii_digit() {
+            return Err(Diagnosis::at(ArnErrorReason::InvalidCharacter(c), i, c));
         }
     }
 
+    if account_id.len() != digits {
+        return Err(Diagnosis::new(ArnErrorReason::InvalidLength, 0..account_id.len()));
+    }
+
     Ok(())
 }
 
@@ -117,19 +196,31 @@ enum RegionParseSection {
 ///
 /// If `region` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidRegion] error is returned.
 pub fn validate_region(region: &str) -> Result<(), ArnError> {
+    check_region(region).map_err(|_| ArnError::InvalidRegion(region.to_string()))
+}
+
+/// Check a region against the rules of [validate_region], diagnosing any failure.
+pub(crate) fn check_region(region: &str) -> Result<(), Diagnosis> {
     // As a special case, we accept the region "local"
     if region == "local" {
         return Ok(());
     }
 
+    if region.is_empty() {
+        return Err(Diagnosis::new(ArnErrorReason::Empty, 0..0));
+    }
+
     let mut section = RegionParseSection::Region;
     let mut state = RegionParseState::Start;
 
-    for c in region.chars() {
+    for (i, c) in region.char_indices() {
         if c == '-' {
             match state {
-                RegionParseState::Start | RegionParseState::LastWasDash => {
-                    return Err(ArnError::InvalidRegion(region.to_string()));
+                RegionParseState::Start => {
+                    return Err(Diagnosis::at(ArnErrorReason::LeadingDash, i, c));
+                }
+                RegionParseState::LastWasDash => {
+                    return Err(Diagnosis::at(ArnErrorReason::ConsecutiveDashes, i, c));
                 }
                 RegionParseState::LastWasAlpha => {
                     state = RegionParseState::LastWasDash;
@@ -140,7 +231,7 @@ pub fn validate_region(region: &str) -> Result<(), ArnError> {
                         state = RegionParseState::LastWasDash;
                     }
                     RegionParseSection::LocalRegion => {
-                        return Err(ArnError::InvalidRegion(region.to_string()));
+                        return Err(Diagnosis::at(ArnErrorReason::UnexpectedCharacter(c), i, c));
                     }
                 },
             }
@@ -150,7 +241,7 @@ pub fn validate_region(region: &str) -> Result<(), ArnError> {
                     state = RegionParseState::LastWasAlpha;
                 }
                 _ => {
-                    return Err(ArnError::InvalidRegion(region.to_string()));
+                    return Err(Diagnosis::at(ArnErrorReason::UnexpectedCharacter(c), i, c));
                 }
             }
         } else if c.is_ascii_digit() {
@@ -159,18 +250,20 @@ pub fn validate_region(region: &str) -> Result<(), ArnError> {
                     state = RegionParseState::LastWasDigit;
                 }
                 _ => {
-                    return Err(ArnError::InvalidRegion(region.to_string()));
+                    return Err(Diagnosis::at(ArnErrorReason::UnexpectedCharacter(c), i, c));
                 }
             }
         } else {
-            return Err(ArnError::InvalidRegion(region.to_string()));
+            return Err(Diagnosis::at(ArnErrorReason::InvalidCharacter(c), i, c));
         }
     }
 
-    if state == RegionParseState::LastWasDigit {
-        Ok(())
-    } else {
-        Err(ArnError::InvalidRegion(region.to_string()))
+    match state {
+        RegionParseState::LastWasDigit => Ok(()),
+        RegionParseState::LastWasDash => {
+            Err(Diagnosis::new(ArnErrorReason::TrailingDash, region.len() - 1..region.len()))
+        }
+        _ => Err(Diagnosis::new(ArnErrorReason::IncompleteRegion, region.len()..region.len())),
     }
 }
 
@@ -187,30 +280,45 @@ pub fn validate_region(region: &str) -> Result<(), ArnError> {
 /// If `service` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidService] error is
 /// returned.
 pub fn validate_service(service: &str) -> Result<(), ArnError> {
+    check_service(service).map_err(|_| ArnError::InvalidService(service.to_string()))
+}
+
+/// Check a service against the rules of [validate_service], diagnosing any failure.
+pub(crate) fn check_service(service: &str) -> Result<(), Diagnosis> {
     if service.is_empty() {
-        return Err(ArnError::InvalidService(service.to_string()));
+        return Err(Diagnosis::new(ArnErrorReason::Empty, 0..0));
     }
 
-    let mut last_was_dash = true;
+    check_dashed(service, |c| c.is_alphanumeric() && !c.is_uppercase(), None)
+}
 
-    for c in service.chars() {
-        if c.is_alphanumeric() && !c.is_uppercase() {
-            last_was_dash = false;
-        } else if c == '-' {
-            if last_was_dash {
-                return Err(ArnError::InvalidService(service.to_string()));
-            }
+/// The maximum length of an ARN, in characters, accepted by AWS.
+pub const MAX_ARN_LENGTH: usize = 2048;
 
-            last_was_dash = true;
-        } else {
-            return Err(ArnError::InvalidService(service.to_string()));
-        }
+/// Verify that a resource meets the requirements for ARNs.
+///
+/// The formatting of resources is service-specific. In this validator, we require only:
+///
+/// *   The resource must not be empty.
+/// *   The resource must not contain ASCII control characters (codepoints `\u{0000}` through `\u{001f}` and
+///     `\u{007f}`).
+///
+/// The overall length of the ARN is limited separately to [MAX_ARN_LENGTH] characters.
+///
+/// If `resource` meets the requirements, Ok is returned. Otherwise, a [ArnError::InvalidResource] error is returned.
+pub fn validate_resource(resource: &str) -> Result<(), ArnError> {
+    check_resource(resource).map_err(|_| ArnError::InvalidResource(resource.to_string()))
+}
+
+/// Check a resource against the rules of [validate_resource], diagnosing any failure.
+pub(crate) fn check_resource(resource: &str) -> Result<(), Diagnosis> {
+    if resource.is_empty() {
+        return Err(Diagnosis::new(ArnErrorReason::Empty, 0..0));
     }
 
-    if last_was_dash {
-        Err(ArnError::InvalidService(service.to_string()))
-    } else {
-        Ok(())
+    match resource.char_indices().find(|(_, c)| c.is_ascii_control()) {
+        Some((i, c)) => Err(Diagnosis::at(ArnErrorReason::InvalidCharacter(c), i, c)),
+        None => Ok(()),
     }
 }
 
@@ -222,5 +330,23 @@ mod test {
         assert!(super::validate_service("kafka-cluster").is_ok());
         assert!(super::validate_service("execute-api").is_ok());
     }
+
+    #[test]
+    fn check_resources() {
+        assert!(super::validate_resource("bucket").is_ok());
+        assert!(super::validate_resource("object/with spaces and ünïcödé").is_ok());
+        assert!(super::validate_resource("").is_err());
+        assert!(super::validate_resource("tab\there").is_err());
+        assert!(super::validate_resource("newline\n").is_err());
+        assert!(super::validate_resource("del\x7f").is_err());
+    }
+
+    #[test]
+    fn check_account_id_digits() {
+        assert!(super::validate_account_id_digits("1234567890", 10).is_ok());
+        assert!(super::validate_account_id_digits("aws", 10).is_ok());
+        assert!(super::validate_account_id_digits("123456789012", 10).is_err());
+        assert!(super::validate_account_id_digits("123456789a", 10).is_err());
+    }
 }
 // end tests -- do not delete; needed for coverage.
@@ -1,9 +1,6 @@
 use {
     crate::{utils::validate_name, PrincipalError},
-    scratchstack_arn::{
-        utils::{validate_account_id, validate_partition},
-        Arn,
-    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
     std::{
         fmt::{Display, Formatter, Result as FmtResult},
         str::FromStr,
@@ -35,13 +32,13 @@ impl AssumedRole {
     ///
     /// * `partition`: The partition this principal exists in.
     /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
-    ///     [PrincipalError::InvalidAccountId] error will be returned.
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
     /// * `role_name`: The name of the role being assumed. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidRoleName] error will be returned:
+    ///   [PrincipalError::InvalidRoleName] error will be returned:
     ///     *   The name must contain between 1 and 64 characters.
     ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
     /// * `session_name`: A name to assign to the session. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidSessionName] error will be returned:
+    ///   [PrincipalError::InvalidSessionName] error will be returned:
     ///     *   The session name must contain between 2 and 64 characters.
     ///     *   The session name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
     ///
@@ -61,8 +58,22 @@ impl AssumedRole {
     /// assert_eq!(assumed_role.session_name(), "session-name");
     /// ```
     pub fn new(partition: &str, account_id: &str, role_name: &str, session_name: &str) -> Result<Self, PrincipalError> {
-        validate_partition(partition)?;
-        validate_account_id(account_id)?;
+        Self::new_with_profile(partition, account_id, role_name, session_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [AssumedRole] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [AssumedRole::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        role_name: &str,
+        session_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
         validate_name(role_name, 64, PrincipalError::InvalidRoleName)?;
         validate_name(session_name, 64, PrincipalError::InvalidSessionName)?;
 
@@ -124,14 +135,16 @@ impl FromStr for AssumedRole {
 
 impl From<&AssumedRole> for Arn {
     fn from(role: &AssumedRole) -> Arn {
-        Arn::new(
-            &role.partition,
-            "sts",
-            "",
-            &role.account_id,
-            &format!("assumed-role/{}/{}", role.role_name, role.session_name),
-        )
-        .unwrap()
+        // Safety: The partition and account id were validated when the assumed role was created.
+        unsafe {
+            Arn::new_unchecked(
+                &role.partition,
+                "sts",
+                "",
+                &role.account_id,
+                &format!("assumed-role/{}/{}", role.role_name, role.session_name),
+            )
+        }
     }
 }
 
@@ -39,9 +39,18 @@ pub enum PrincipalError {
     /// Invalid instance profile id. The argument contains the specified instance profile id.
     InvalidInstanceProfileId(String),
 
+    /// Invalid MFA device name. The argument contains the specified MFA device name.
+    InvalidMfaDeviceName(String),
+
+    /// Invalid OpenID Connect provider URL. The argument contains the specified URL.
+    InvalidOidcProviderUrl(String),
+
     /// Invalid IAM path. The argument contains the specified path.
     InvalidPath(String),
 
+    /// Invalid policy name. The argument contains the specified policy name.
+    InvalidPolicyName(String),
+
     /// Invalid region. The argument contains the specified region.
     InvalidRegion(String),
 
@@ -54,9 +63,15 @@ pub enum PrincipalError {
     /// Invalid role id. The argument contains the specified role id.
     InvalidRoleId(String),
 
+    /// Invalid SAML provider name. The argument contains the specified SAML provider name.
+    InvalidSamlProviderName(String),
+
     /// Invalid scheme. The argument contains the specified scheme.
     InvalidScheme(String),
 
+    /// Invalid server certificate name. The argument contains the specified server certificate name.
+    InvalidServerCertificateName(String),
+
     /// Invalid service. The argument contains the specified service name.
     InvalidService(String),
 
@@ -94,13 +109,22 @@ impl Display for PrincipalError {
             Self::InvalidInstanceProfileId(instance_profile_id) => {
                 write!(f, "Invalid instance profile id: {instance_profile_id:#?}")
             }
+            Self::InvalidMfaDeviceName(mfa_device_name) => write!(f, "Invalid MFA device name: {mfa_device_name:#?}"),
+            Self::InvalidOidcProviderUrl(url) => write!(f, "Invalid OpenID Connect provider URL: {url:#?}"),
             Self::InvalidPartition(partition) => write!(f, "Invalid partition: {partition:#?}"),
             Self::InvalidPath(path) => write!(f, "Invalid path: {path:#?}"),
+            Self::InvalidPolicyName(policy_name) => write!(f, "Invalid policy name: {policy_name:#?}"),
             Self::InvalidRegion(region) => write!(f, "Invalid region: {region:#?}"),
             Self::InvalidResource(resource) => write!(f, "Invalid resource: {resource:#?}"),
             Self::InvalidRoleName(role_name) => write!(f, "Invalid role name: {role_name:#?}"),
             Self::InvalidRoleId(role_id) => write!(f, "Invalid role id: {role_id:#?}"),
+            Self::InvalidSamlProviderName(saml_provider_name) => {
+                write!(f, "Invalid SAML provider name: {saml_provider_name:#?}")
+            }
             Self::InvalidScheme(scheme) => write!(f, "Invalid scheme: {scheme:#?}"),
+            Self::InvalidServerCertificateName(server_certificate_name) => {
+                write!(f, "Invalid server certificate name: {server_certificate_name:#?}")
+            }
             Self::InvalidService(service_name) => {
                 write!(f, "Invalid service name: {service_name:#?}")
             }
@@ -131,16 +155,6 @@ impl From<ArnError> for PrincipalError {
 mod tests {
     use {super::PrincipalError, scratchstack_arn::ArnError};
 
-    #[test]
-    fn exercise_unused_in_crate() {
-        // These errors are not created in code used here, but are used in other crates.
-        let err = PrincipalError::InvalidGroupName("test+1".to_string());
-        assert_eq!(err.to_string(), r#"Invalid group name: "test+1""#);
-
-        let err = PrincipalError::InvalidInstanceProfileName("test+1".to_string());
-        assert_eq!(err.to_string(), r#"Invalid instance profile name: "test+1""#);
-    }
-
     fn check_arn_err_into(arn_err: ArnError) {
         let arn_err_string = arn_err.to_string();
         let principal_err = PrincipalError::from(arn_err);
@@ -1,9 +1,6 @@
 use {
     crate::{utils::validate_name, PrincipalError},
-    scratchstack_arn::{
-        utils::{validate_account_id, validate_partition},
-        Arn,
-    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
     std::fmt::{Display, Formatter, Result as FmtResult},
 };
 
@@ -27,9 +24,9 @@ impl FederatedUser {
     ///
     /// * `partition`: The partition this principal exists in.
     /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
-    ///     [PrincipalError::InvalidAccountId] error will be returned.
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
     /// * `user_name`: The name of the federated user. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidFederatedUserName] error will be returned:
+    ///   [PrincipalError::InvalidFederatedUserName] error will be returned:
     ///     *   The name must contain between 2 and 32 characters.
     ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
     ///
@@ -46,8 +43,21 @@ impl FederatedUser {
     /// assert_eq!(federated_user.user_name(), "user@example.com");
     /// ```
     pub fn new(partition: &str, account_id: &str, user_name: &str) -> Result<Self, PrincipalError> {
-        validate_partition(partition)?;
-        validate_account_id(account_id)?;
+        Self::new_with_profile(partition, account_id, user_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [FederatedUser] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [FederatedUser::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        user_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
         validate_name(user_name, 32, PrincipalError::InvalidFederatedUserName)?;
 
         if user_name.len() < 2 {
@@ -82,7 +92,16 @@ impl FederatedUser {
 
 impl From<&FederatedUser> for Arn {
     fn from(user: &FederatedUser) -> Arn {
-        Arn::new(&user.partition, "sts", "", &user.account_id, &format!("federated-user/{}", user.user_name)).unwrap()
+        // Safety: The partition and account id were validated when the federated user was created.
+        unsafe {
+            Arn::new_unchecked(
+                &user.partition,
+                "sts",
+                "",
+                &user.account_id,
+                &format!("federated-user/{}", user.user_name),
+            )
+        }
     }
 }
 
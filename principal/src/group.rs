@@ -0,0 +1,194 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM group.
+///
+/// Group structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Group {
+    /// The partition this group exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the group, case-insensitive.
+    group_name: String,
+}
+
+impl Group {
+    /// Create a [Group] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this group exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the group is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `group_name`: The name of the group. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidGroupName] error will be returned:
+    ///     *   The name must contain between 1 and 128 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, a [Group] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, path: &str, group_name: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, group_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [Group] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [Group::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        group_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(group_name, 128, PrincipalError::InvalidGroupName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            group_name: group_name.into(),
+        })
+    }
+
+    /// The partition of the group.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the group.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the group.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the group.
+    #[inline]
+    pub fn group_name(&self) -> &str {
+        &self.group_name
+    }
+}
+
+impl From<&Group> for Arn {
+    fn from(group: &Group) -> Arn {
+        // Safety: The partition and account id were validated when the group was created.
+        unsafe {
+            Arn::new_unchecked(
+                &group.partition,
+                "iam",
+                "",
+                &group.account_id,
+                &format!("group{}{}", group.path, group.group_name),
+            )
+        }
+    }
+}
+
+impl FromStr for Group {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning a [Group] if the ARN is a valid group ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for Group {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM group, convert it to a [Group]; otherwise, return a [PrincipalError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, group_name) = split_iam_resource(arn, "group")?;
+        Self::new(arn.partition(), arn.account_id(), path, group_name)
+    }
+}
+
+impl Display for Group {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:group{}{}", self.partition, self.account_id, self.path, self.group_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::Group, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let group = Group::new("aws", "123456789012", "/my/path/", "group-name").unwrap();
+        assert_eq!(group.partition(), "aws");
+        assert_eq!(group.account_id(), "123456789012");
+        assert_eq!(group.path(), "/my/path/");
+        assert_eq!(group.group_name(), "group-name");
+        assert_eq!(group.to_string(), "arn:aws:iam::123456789012:group/my/path/group-name");
+
+        let arn: Arn = (&group).into();
+        assert_eq!(arn.to_string(), group.to_string());
+        assert_eq!(Group::try_from(&arn).unwrap(), group);
+        assert_eq!(Group::from_str("arn:aws:iam::123456789012:group/group-name").unwrap().path(), "/");
+    }
+
+    #[test]
+    fn check_invalid() {
+        let err = Group::new("aws", "123456789012", "/", "bad!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid group name: "bad!name""#);
+
+        let long_name = "n".repeat(129);
+        let err = Group::new("aws", "123456789012", "/", &long_name).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid group name: "{long_name}""#));
+        Group::new("aws", "123456789012", "/", &long_name[1..]).unwrap();
+
+        let err = Group::new("aws", "123456789012", "/path", "group-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "/path""#);
+
+        let err = Group::from_str("arn:aws:iam::123456789012:group/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid group name: """#);
+
+        let err = Group::from_str("arn:aws:iam::123456789012:group").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "group""#);
+
+        let err = Group::from_str("arn:aws:iam::123456789012:user/group-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/group-name""#);
+
+        let err = Group::from_str("arn:aws:s3::123456789012:group/group-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "s3""#);
+
+        let err = Group::from_str("arn:aws:iam:us-east-1:123456789012:group/group-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
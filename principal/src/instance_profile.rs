@@ -0,0 +1,207 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM instance profile.
+///
+/// InstanceProfile structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InstanceProfile {
+    /// The partition this instance profile exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the instance profile, case-insensitive.
+    instance_profile_name: String,
+}
+
+impl InstanceProfile {
+    /// Create an [InstanceProfile] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this instance profile exists in. This must meet the rules of the
+    ///   [ValidationProfile] or a [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the instance profile is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `instance_profile_name`: The name of the instance profile. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidInstanceProfileName] error will be returned:
+    ///     *   The name must contain between 1 and 128 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, an [InstanceProfile] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        instance_profile_name: &str,
+    ) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, instance_profile_name, &ScratchstackRelaxed)
+    }
+
+    /// Create an [InstanceProfile] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [InstanceProfile::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        instance_profile_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(instance_profile_name, 128, PrincipalError::InvalidInstanceProfileName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            instance_profile_name: instance_profile_name.into(),
+        })
+    }
+
+    /// The partition of the instance profile.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the instance profile.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the instance profile.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the instance profile.
+    #[inline]
+    pub fn instance_profile_name(&self) -> &str {
+        &self.instance_profile_name
+    }
+}
+
+impl From<&InstanceProfile> for Arn {
+    fn from(instance_profile: &InstanceProfile) -> Arn {
+        // Safety: The partition and account id were validated when the instance profile was created.
+        unsafe {
+            Arn::new_unchecked(
+                &instance_profile.partition,
+                "iam",
+                "",
+                &instance_profile.account_id,
+                &format!("instance-profile{}{}", instance_profile.path, instance_profile.instance_profile_name),
+            )
+        }
+    }
+}
+
+impl FromStr for InstanceProfile {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning an [InstanceProfile] if the ARN is a valid instance profile ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for InstanceProfile {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM instance profile, convert it to an [InstanceProfile]; otherwise, return a
+    /// [PrincipalError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, instance_profile_name) = split_iam_resource(arn, "instance-profile")?;
+        Self::new(arn.partition(), arn.account_id(), path, instance_profile_name)
+    }
+}
+
+impl Display for InstanceProfile {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "arn:{}:iam::{}:instance-profile{}{}",
+            self.partition, self.account_id, self.path, self.instance_profile_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::InstanceProfile, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let instance_profile = InstanceProfile::new("aws", "123456789012", "/my/path/", "profile-name").unwrap();
+        assert_eq!(instance_profile.partition(), "aws");
+        assert_eq!(instance_profile.account_id(), "123456789012");
+        assert_eq!(instance_profile.path(), "/my/path/");
+        assert_eq!(instance_profile.instance_profile_name(), "profile-name");
+        assert_eq!(instance_profile.to_string(), "arn:aws:iam::123456789012:instance-profile/my/path/profile-name");
+
+        let arn: Arn = (&instance_profile).into();
+        assert_eq!(arn.to_string(), instance_profile.to_string());
+        assert_eq!(InstanceProfile::try_from(&arn).unwrap(), instance_profile);
+        assert_eq!(
+            InstanceProfile::from_str("arn:aws:iam::123456789012:instance-profile/profile-name").unwrap().path(),
+            "/"
+        );
+    }
+
+    #[test]
+    fn check_invalid() {
+        let err = InstanceProfile::new("aws", "123456789012", "/", "bad!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid instance profile name: "bad!name""#);
+
+        let long_name = "n".repeat(129);
+        let err = InstanceProfile::new("aws", "123456789012", "/", &long_name).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid instance profile name: "{long_name}""#));
+        InstanceProfile::new("aws", "123456789012", "/", &long_name[1..]).unwrap();
+
+        let err = InstanceProfile::new("aws", "123456789012", "/path", "profile-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "/path""#);
+
+        let err = InstanceProfile::from_str("arn:aws:iam::123456789012:instance-profile/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid instance profile name: """#);
+
+        let err = InstanceProfile::from_str("arn:aws:iam::123456789012:instance-profile").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "instance-profile""#);
+
+        let err = InstanceProfile::from_str("arn:aws:iam::123456789012:user/profile-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/profile-name""#);
+
+        let err = InstanceProfile::from_str("arn:aws:s3::123456789012:instance-profile/profile-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "s3""#);
+
+        let err =
+            InstanceProfile::from_str("arn:aws:iam:us-east-1:123456789012:instance-profile/profile-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -23,8 +23,16 @@ mod assumed_role;
 mod canonical_user;
 mod error;
 mod federated_user;
+mod group;
+mod instance_profile;
+mod mfa_device;
+mod oidc_provider;
+mod policy;
 mod principal;
+mod role;
 mod root_user;
+mod saml_provider;
+mod server_certificate;
 mod service;
 mod session;
 mod user;
@@ -37,8 +45,16 @@ pub use {
     canonical_user::CanonicalUser,
     error::PrincipalError,
     federated_user::FederatedUser,
+    group::Group,
+    instance_profile::InstanceProfile,
+    mfa_device::MfaDevice,
+    oidc_provider::OidcProvider,
+    policy::Policy,
     principal::{Principal, PrincipalIdentity, PrincipalSource},
+    role::Role,
     root_user::RootUser,
+    saml_provider::SamlProvider,
+    server_certificate::ServerCertificate,
     service::Service,
     session::{SessionData, SessionValue},
     user::User,
@@ -0,0 +1,194 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM virtual MFA device.
+///
+/// MfaDevice structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MfaDevice {
+    /// The partition this MFA device exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the MFA device, case-insensitive.
+    mfa_device_name: String,
+}
+
+impl MfaDevice {
+    /// Create an [MfaDevice] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this MFA device exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the MFA device is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `mfa_device_name`: The name of the MFA device. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidMfaDeviceName] error will be returned:
+    ///     *   The name must contain between 1 and 226 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, an [MfaDevice] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, path: &str, mfa_device_name: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, mfa_device_name, &ScratchstackRelaxed)
+    }
+
+    /// Create an [MfaDevice] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [MfaDevice::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        mfa_device_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(mfa_device_name, 226, PrincipalError::InvalidMfaDeviceName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            mfa_device_name: mfa_device_name.into(),
+        })
+    }
+
+    /// The partition of the MFA device.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the MFA device.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the MFA device.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the MFA device.
+    #[inline]
+    pub fn mfa_device_name(&self) -> &str {
+        &self.mfa_device_name
+    }
+}
+
+impl From<&MfaDevice> for Arn {
+    fn from(device: &MfaDevice) -> Arn {
+        // Safety: The partition and account id were validated when the MFA device was created.
+        unsafe {
+            Arn::new_unchecked(
+                &device.partition,
+                "iam",
+                "",
+                &device.account_id,
+                &format!("mfa{}{}", device.path, device.mfa_device_name),
+            )
+        }
+    }
+}
+
+impl FromStr for MfaDevice {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning an [MfaDevice] if the ARN is a valid MFA device ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for MfaDevice {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM MFA device, convert it to an [MfaDevice]; otherwise, return a
+    /// [PrincipalError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, mfa_device_name) = split_iam_resource(arn, "mfa")?;
+        Self::new(arn.partition(), arn.account_id(), path, mfa_device_name)
+    }
+}
+
+impl Display for MfaDevice {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:mfa{}{}", self.partition, self.account_id, self.path, self.mfa_device_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::MfaDevice, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let device = MfaDevice::new("aws", "123456789012", "/my/path/", "device-name").unwrap();
+        assert_eq!(device.partition(), "aws");
+        assert_eq!(device.account_id(), "123456789012");
+        assert_eq!(device.path(), "/my/path/");
+        assert_eq!(device.mfa_device_name(), "device-name");
+        assert_eq!(device.to_string(), "arn:aws:iam::123456789012:mfa/my/path/device-name");
+
+        let arn: Arn = (&device).into();
+        assert_eq!(arn.to_string(), device.to_string());
+        assert_eq!(MfaDevice::try_from(&arn).unwrap(), device);
+        assert_eq!(MfaDevice::from_str("arn:aws:iam::123456789012:mfa/device-name").unwrap().path(), "/");
+    }
+
+    #[test]
+    fn check_invalid() {
+        let err = MfaDevice::new("aws", "123456789012", "/", "bad!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid MFA device name: "bad!name""#);
+
+        let long_name = "n".repeat(227);
+        let err = MfaDevice::new("aws", "123456789012", "/", &long_name).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid MFA device name: "{long_name}""#));
+        MfaDevice::new("aws", "123456789012", "/", &long_name[1..]).unwrap();
+
+        let err = MfaDevice::new("aws", "123456789012", "/path", "device-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "/path""#);
+
+        let err = MfaDevice::from_str("arn:aws:iam::123456789012:mfa/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid MFA device name: """#);
+
+        let err = MfaDevice::from_str("arn:aws:iam::123456789012:mfa").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "mfa""#);
+
+        let err = MfaDevice::from_str("arn:aws:iam::123456789012:user/device-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/device-name""#);
+
+        let err = MfaDevice::from_str("arn:aws:s3::123456789012:mfa/device-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "s3""#);
+
+        let err = MfaDevice::from_str("arn:aws:iam:us-east-1:123456789012:mfa/device-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,208 @@
+use {
+    crate::{
+        utils::{strip_iam_resource_type, validate_dns},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM OpenID Connect (OIDC) identity provider.
+///
+/// The provider is identified by its URL without the `https://` scheme, such as `token.actions.githubusercontent.com`
+/// or `oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE`.
+///
+/// OidcProvider structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OidcProvider {
+    /// The partition this provider exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// The URL of the provider, without the scheme.
+    url: String,
+}
+
+impl OidcProvider {
+    /// Create an [OidcProvider] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this provider exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `url`: The URL of the provider. A leading `https://` is removed. This must meet the following requirements or
+    ///   a [PrincipalError::InvalidOidcProviderUrl] error will be returned:
+    ///     *   The URL must contain between 1 and 255 characters.
+    ///     *   The host must meet the requirements of [validate_dns].
+    ///     *   The path, if any, must be composed of characters in the ASCII range 0x21 (`!`) through 0x7E (`~`).
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, an [OidcProvider] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, url: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, url, &ScratchstackRelaxed)
+    }
+
+    /// Create an [OidcProvider] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [OidcProvider::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        url: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+
+        let url = url.strip_prefix("https://").unwrap_or(url);
+        if url.is_empty() || url.len() > 255 {
+            return Err(PrincipalError::InvalidOidcProviderUrl(url.to_string()));
+        }
+
+        let (host, path) = url.split_once('/').unwrap_or((url, ""));
+        validate_dns(host, 255, PrincipalError::InvalidOidcProviderUrl)
+            .map_err(|_| PrincipalError::InvalidOidcProviderUrl(url.to_string()))?;
+
+        if !path.bytes().all(|c| (0x21..=0x7e).contains(&c)) {
+            return Err(PrincipalError::InvalidOidcProviderUrl(url.to_string()));
+        }
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            url: url.into(),
+        })
+    }
+
+    /// The partition of the provider.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the provider.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The URL of the provider, without the `https://` scheme.
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The host name of the provider.
+    #[inline]
+    pub fn host(&self) -> &str {
+        self.url.split_once('/').map_or(self.url.as_str(), |(host, _)| host)
+    }
+}
+
+impl From<&OidcProvider> for Arn {
+    fn from(provider: &OidcProvider) -> Arn {
+        // Safety: The partition and account id were validated when the provider was created.
+        unsafe {
+            Arn::new_unchecked(
+                &provider.partition,
+                "iam",
+                "",
+                &provider.account_id,
+                &format!("oidc-provider/{}", provider.url),
+            )
+        }
+    }
+}
+
+impl FromStr for OidcProvider {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning an [OidcProvider] if the ARN is a valid OpenID Connect provider ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for OidcProvider {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid OpenID Connect provider, convert it to an [OidcProvider]; otherwise, return a
+    /// [PrincipalError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let url = strip_iam_resource_type(arn, "oidc-provider")?;
+
+        // The URL in an ARN never has a scheme.
+        if url.starts_with("https:") {
+            return Err(PrincipalError::InvalidOidcProviderUrl(url.to_string()));
+        }
+
+        Self::new(arn.partition(), arn.account_id(), url)
+    }
+}
+
+impl Display for OidcProvider {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:oidc-provider/{}", self.partition, self.account_id, self.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::OidcProvider, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let provider = OidcProvider::new("aws", "123456789012", "https://token.actions.githubusercontent.com").unwrap();
+        assert_eq!(provider.partition(), "aws");
+        assert_eq!(provider.account_id(), "123456789012");
+        assert_eq!(provider.url(), "token.actions.githubusercontent.com");
+        assert_eq!(provider.host(), "token.actions.githubusercontent.com");
+        assert_eq!(provider.to_string(), "arn:aws:iam::123456789012:oidc-provider/token.actions.githubusercontent.com");
+
+        let arn: Arn = (&provider).into();
+        assert_eq!(arn.to_string(), provider.to_string());
+        assert_eq!(OidcProvider::try_from(&arn).unwrap(), provider);
+
+        let provider = OidcProvider::from_str(
+            "arn:aws:iam::123456789012:oidc-provider/oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE",
+        )
+        .unwrap();
+        assert_eq!(provider.host(), "oidc.eks.us-west-2.amazonaws.com");
+        assert_eq!(provider.url(), "oidc.eks.us-west-2.amazonaws.com/id/EXAMPLED539D4633E53DE1B71EXAMPLE");
+    }
+
+    #[test]
+    fn check_invalid() {
+        for url in ["", "-bad.example.com", "bad..example.com", "example.com/with space"] {
+            let err = OidcProvider::new("aws", "123456789012", url).unwrap_err();
+            assert_eq!(err.to_string(), format!("Invalid OpenID Connect provider URL: {url:?}"), "{url}");
+        }
+
+        let err = OidcProvider::new("aws", "123456789012", &format!("example.com/{}", "p".repeat(244))).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid OpenID Connect provider URL"));
+
+        let err = OidcProvider::from_str("arn:aws:iam::123456789012:oidc-provider/https://example.com").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid OpenID Connect provider URL: "https://example.com""#);
+
+        let err = OidcProvider::from_str("arn:aws:iam::123456789012:oidc/example.com").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "oidc/example.com""#);
+
+        let err = OidcProvider::from_str("arn:aws:sts::123456789012:oidc-provider/example.com").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "sts""#);
+
+        let err = OidcProvider::from_str("arn:aws:iam:us-east-1:123456789012:oidc-provider/example.com").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,208 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM managed policy.
+///
+/// Policy structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Policy {
+    /// The partition this policy exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the policy, case-insensitive.
+    policy_name: String,
+}
+
+impl Policy {
+    /// Create a [Policy] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this policy exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id, or `aws` for AWS managed policies. This must be composed of 12
+    ///   ASCII digits or be `aws`, or a [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the policy is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `policy_name`: The name of the policy. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidPolicyName] error will be returned:
+    ///     *   The name must contain between 1 and 128 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, a [Policy] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, path: &str, policy_name: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, policy_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [Policy] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [Policy::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        policy_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(policy_name, 128, PrincipalError::InvalidPolicyName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            policy_name: policy_name.into(),
+        })
+    }
+
+    /// The partition of the policy.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the policy.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the policy.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the policy.
+    #[inline]
+    pub fn policy_name(&self) -> &str {
+        &self.policy_name
+    }
+
+    /// Indicates whether this is an AWS managed policy, i.e. the account id is `aws`.
+    #[inline]
+    pub fn is_aws_managed(&self) -> bool {
+        self.account_id == "aws"
+    }
+}
+
+impl From<&Policy> for Arn {
+    fn from(policy: &Policy) -> Arn {
+        // Safety: The partition and account id were validated when the policy was created.
+        unsafe {
+            Arn::new_unchecked(
+                &policy.partition,
+                "iam",
+                "",
+                &policy.account_id,
+                &format!("policy{}{}", policy.path, policy.policy_name),
+            )
+        }
+    }
+}
+
+impl FromStr for Policy {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning a [Policy] if the ARN is a valid policy ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for Policy {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM policy, convert it to a [Policy]; otherwise, return a [PrincipalError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, policy_name) = split_iam_resource(arn, "policy")?;
+        Self::new(arn.partition(), arn.account_id(), path, policy_name)
+    }
+}
+
+impl Display for Policy {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:policy{}{}", self.partition, self.account_id, self.path, self.policy_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::Policy, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let policy = Policy::new("aws", "123456789012", "/my/path/", "policy-name").unwrap();
+        assert_eq!(policy.partition(), "aws");
+        assert_eq!(policy.account_id(), "123456789012");
+        assert_eq!(policy.path(), "/my/path/");
+        assert_eq!(policy.policy_name(), "policy-name");
+        assert_eq!(policy.to_string(), "arn:aws:iam::123456789012:policy/my/path/policy-name");
+
+        let arn: Arn = (&policy).into();
+        assert_eq!(arn.to_string(), policy.to_string());
+        assert_eq!(Policy::try_from(&arn).unwrap(), policy);
+        assert_eq!(Policy::from_str("arn:aws:iam::123456789012:policy/policy-name").unwrap().path(), "/");
+        assert!(!policy.is_aws_managed());
+
+        let policy = Policy::from_str("arn:aws:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole").unwrap();
+        assert!(policy.is_aws_managed());
+        assert_eq!(policy.account_id(), "aws");
+        assert_eq!(policy.path(), "/service-role/");
+        assert_eq!(policy.policy_name(), "AWSLambdaBasicExecutionRole");
+        assert_eq!(policy.to_string(), "arn:aws:iam::aws:policy/service-role/AWSLambdaBasicExecutionRole");
+    }
+
+    #[test]
+    fn check_invalid() {
+        let err = Policy::new("aws", "123456789012", "/", "bad!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid policy name: "bad!name""#);
+
+        let long_name = "n".repeat(129);
+        let err = Policy::new("aws", "123456789012", "/", &long_name).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid policy name: "{long_name}""#));
+        Policy::new("aws", "123456789012", "/", &long_name[1..]).unwrap();
+
+        let err = Policy::new("aws", "123456789012", "/path", "policy-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "/path""#);
+
+        let err = Policy::from_str("arn:aws:iam::123456789012:policy/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid policy name: """#);
+
+        let err = Policy::from_str("arn:aws:iam::123456789012:policy").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "policy""#);
+
+        let err = Policy::from_str("arn:aws:iam::123456789012:user/policy-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/policy-name""#);
+
+        let err = Policy::from_str("arn:aws:s3::123456789012:policy/policy-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "s3""#);
+
+        let err = Policy::from_str("arn:aws:iam:us-east-1:123456789012:policy/policy-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -340,10 +340,8 @@ impl PrincipalIdentity {
             "sts" if resource.starts_with("assumed-role/") => {
                 return Ok(AssumedRole::try_from(&parsed_arn)?.into());
             }
-            "iam" => {
-                if resource.starts_with("user/") {
-                    return Ok(User::try_from(&parsed_arn)?.into());
-                }
+            "iam" if resource.starts_with("user/") => {
+                return Ok(User::try_from(&parsed_arn)?.into());
             }
             _ => {}
         }
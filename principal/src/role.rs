@@ -0,0 +1,251 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// The path prefix for service-linked roles.
+const SERVICE_LINKED_ROLE_PATH: &str = "/aws-service-role/";
+
+/// Details about an AWS IAM role.
+///
+/// Role structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Role {
+    /// The partition this role exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the role, case-insensitive.
+    role_name: String,
+}
+
+impl Role {
+    /// Create a [Role] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this role exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the role is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `role_name`: The name of the role. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidRoleName] error will be returned:
+    ///     *   The name must contain between 1 and 64 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, a [Role] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, path: &str, role_name: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, role_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [Role] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [Role::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        role_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(role_name, 64, PrincipalError::InvalidRoleName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            role_name: role_name.into(),
+        })
+    }
+
+    /// The partition of the role.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the role.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the role.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the role.
+    #[inline]
+    pub fn role_name(&self) -> &str {
+        &self.role_name
+    }
+
+    /// If this is a service-linked role (under the path `/aws-service-role/<service>/`), return the service
+    /// principal the role is linked to, such as `elasticbeanstalk.amazonaws.com`. Otherwise, return `None`.
+    pub fn service_linked_service(&self) -> Option<&str> {
+        let service = self.path.strip_prefix(SERVICE_LINKED_ROLE_PATH)?.strip_suffix('/')?;
+        if service.is_empty() || service.contains('/') {
+            None
+        } else {
+            Some(service)
+        }
+    }
+
+    /// Indicates whether this is a service-linked role.
+    #[inline]
+    pub fn is_service_linked(&self) -> bool {
+        self.service_linked_service().is_some()
+    }
+}
+
+impl From<&Role> for Arn {
+    fn from(role: &Role) -> Arn {
+        // Safety: The partition and account id were validated when the role was created.
+        unsafe {
+            Arn::new_unchecked(
+                &role.partition,
+                "iam",
+                "",
+                &role.account_id,
+                &format!("role{}{}", role.path, role.role_name),
+            )
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning a [Role] if the ARN is a valid role ARN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use scratchstack_aws_principal::Role;
+    /// # use std::str::FromStr;
+    /// let role = Role::from_str(
+    ///     "arn:aws:iam::123456789012:role/aws-service-role/elasticbeanstalk.amazonaws.com/AWSServiceRoleForElasticBeanstalk",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(role.service_linked_service(), Some("elasticbeanstalk.amazonaws.com"));
+    /// ```
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for Role {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM role, convert it to a [Role]; otherwise, return a [PrincipalError]
+    /// indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, role_name) = split_iam_resource(arn, "role")?;
+        Self::new(arn.partition(), arn.account_id(), path, role_name)
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:role{}{}", self.partition, self.account_id, self.path, self.role_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Role,
+        scratchstack_arn::{Arn, AwsStrict},
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn check_components() {
+        let role = Role::new("aws", "123456789012", "/my/path/", "role-name").unwrap();
+        assert_eq!(role.partition(), "aws");
+        assert_eq!(role.account_id(), "123456789012");
+        assert_eq!(role.path(), "/my/path/");
+        assert_eq!(role.role_name(), "role-name");
+        assert!(!role.is_service_linked());
+        assert_eq!(role.to_string(), "arn:aws:iam::123456789012:role/my/path/role-name");
+
+        let arn: Arn = (&role).into();
+        assert_eq!(arn.to_string(), role.to_string());
+        assert_eq!(Role::try_from(&arn).unwrap(), role);
+
+        let role = Role::new_with_profile("aws", "123456789012", "/", "role-name", &AwsStrict).unwrap();
+        assert_eq!(role.to_string(), "arn:aws:iam::123456789012:role/role-name");
+    }
+
+    #[test]
+    fn check_service_linked() {
+        let role = Role::from_str(
+            "arn:aws:iam::123456789012:role/aws-service-role/elasticbeanstalk.amazonaws.com/AWSServiceRoleForElasticBeanstalk",
+        )
+        .unwrap();
+        assert!(role.is_service_linked());
+        assert_eq!(role.service_linked_service(), Some("elasticbeanstalk.amazonaws.com"));
+        assert_eq!(role.path(), "/aws-service-role/elasticbeanstalk.amazonaws.com/");
+        assert_eq!(role.role_name(), "AWSServiceRoleForElasticBeanstalk");
+
+        for path in ["/aws-service-role/", "/aws-service-role//", "/aws-service-role/a/b/", "/service-role/"] {
+            let role = Role::new("aws", "123456789012", path, "role-name").unwrap();
+            assert!(!role.is_service_linked(), "{path}");
+        }
+    }
+
+    #[test]
+    fn check_invalid_roles() {
+        let err = Role::new("aws", "123456789012", "/", "role!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid role name: "role!name""#);
+
+        let err = Role::new("aws", "123456789012", "/", &"r".repeat(65)).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid role name: "{}""#, "r".repeat(65)));
+
+        let err = Role::new("aws", "123456789012", "path/", "role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "path/""#);
+
+        let err = Role::from_str("arn:aws:iam::123456789012:role/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid role name: """#);
+
+        let err = Role::from_str("arn:aws:iam::123456789012:roles/role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "roles/role-name""#);
+
+        let err = Role::from_str("arn:aws:iam::123456789012:user/role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/role-name""#);
+
+        let err = Role::from_str("arn:aws:sts::123456789012:role/role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "sts""#);
+
+        let err = Role::from_str("arn:aws:iam:us-east-1:123456789012:role/role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+
+        let err = Role::from_str("arn:aws:iam::12345:role/role-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid account id: "12345""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
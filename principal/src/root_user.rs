@@ -1,9 +1,6 @@
 use {
     crate::PrincipalError,
-    scratchstack_arn::{
-        utils::{validate_account_id, validate_partition},
-        Arn,
-    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
     std::fmt::{Display, Formatter, Result as FmtResult},
 };
 
@@ -27,14 +24,26 @@ impl RootUser {
     ///
     /// * `partition` - The partition this principal exists in.
     /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
-    ///     [PrincipalError::InvalidAccountId] error will be returned.
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
     ///
     /// # Return value
     ///
     /// If the requirement is met, a [RootUser] object is returned. Otherwise, a  [PrincipalError] error is returned.
     pub fn new(partition: &str, account_id: &str) -> Result<Self, PrincipalError> {
-        validate_partition(partition)?;
-        validate_account_id(account_id)?;
+        Self::new_with_profile(partition, account_id, &ScratchstackRelaxed)
+    }
+
+    /// Create a [RootUser] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [RootUser::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
 
         Ok(Self {
             partition: partition.into(),
@@ -57,7 +66,8 @@ impl RootUser {
 
 impl From<&RootUser> for Arn {
     fn from(root_user: &RootUser) -> Self {
-        Arn::new(&root_user.partition, "iam", "", &root_user.account_id, "root").unwrap()
+        // Safety: The partition and account id were validated when the root user was created.
+        unsafe { Arn::new_unchecked(&root_user.partition, "iam", "", &root_user.account_id, "root") }
     }
 }
 
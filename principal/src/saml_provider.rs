@@ -0,0 +1,173 @@
+use {
+    crate::{utils::strip_iam_resource_type, PrincipalError},
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM SAML identity provider.
+///
+/// SamlProvider structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SamlProvider {
+    /// The partition this provider exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Name of the provider.
+    saml_provider_name: String,
+}
+
+impl SamlProvider {
+    /// Create a [SamlProvider] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this provider exists in. This must meet the rules of the [ValidationProfile] or a
+    ///   [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `saml_provider_name`: The name of the provider. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidSamlProviderName] error will be returned:
+    ///     *   The name must contain between 1 and 128 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `- . _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, a [SamlProvider] object is returned. Otherwise, a [PrincipalError] error
+    /// is returned.
+    pub fn new(partition: &str, account_id: &str, saml_provider_name: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, saml_provider_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [SamlProvider] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [SamlProvider::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        saml_provider_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+
+        let n_bytes = saml_provider_name.as_bytes();
+        if n_bytes.is_empty()
+            || n_bytes.len() > 128
+            || !n_bytes.iter().all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'.' || *c == b'_')
+        {
+            return Err(PrincipalError::InvalidSamlProviderName(saml_provider_name.to_string()));
+        }
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            saml_provider_name: saml_provider_name.into(),
+        })
+    }
+
+    /// The partition of the provider.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the provider.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The name of the provider.
+    #[inline]
+    pub fn saml_provider_name(&self) -> &str {
+        &self.saml_provider_name
+    }
+}
+
+impl From<&SamlProvider> for Arn {
+    fn from(provider: &SamlProvider) -> Arn {
+        // Safety: The partition and account id were validated when the provider was created.
+        unsafe {
+            Arn::new_unchecked(
+                &provider.partition,
+                "iam",
+                "",
+                &provider.account_id,
+                &format!("saml-provider/{}", provider.saml_provider_name),
+            )
+        }
+    }
+}
+
+impl FromStr for SamlProvider {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning a [SamlProvider] if the ARN is a valid SAML provider ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for SamlProvider {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid SAML provider, convert it to a [SamlProvider]; otherwise, return a
+    /// [PrincipalError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let saml_provider_name = strip_iam_resource_type(arn, "saml-provider")?;
+
+        Self::new(arn.partition(), arn.account_id(), saml_provider_name)
+    }
+}
+
+impl Display for SamlProvider {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "arn:{}:iam::{}:saml-provider/{}", self.partition, self.account_id, self.saml_provider_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::SamlProvider, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let provider = SamlProvider::new("aws", "123456789012", "My_Idp.example-1").unwrap();
+        assert_eq!(provider.partition(), "aws");
+        assert_eq!(provider.account_id(), "123456789012");
+        assert_eq!(provider.saml_provider_name(), "My_Idp.example-1");
+        assert_eq!(provider.to_string(), "arn:aws:iam::123456789012:saml-provider/My_Idp.example-1");
+
+        let arn: Arn = (&provider).into();
+        assert_eq!(arn.to_string(), provider.to_string());
+        assert_eq!(SamlProvider::try_from(&arn).unwrap(), provider);
+    }
+
+    #[test]
+    fn check_invalid() {
+        for name in ["", "bad=name", "with/slash"] {
+            let err = SamlProvider::new("aws", "123456789012", name).unwrap_err();
+            assert_eq!(err.to_string(), format!("Invalid SAML provider name: {name:?}"));
+        }
+        SamlProvider::new("aws", "123456789012", &"n".repeat(128)).unwrap();
+        SamlProvider::new("aws", "123456789012", &"n".repeat(129)).unwrap_err();
+
+        let err = SamlProvider::from_str("arn:aws:iam::123456789012:saml/idp").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "saml/idp""#);
+
+        let err = SamlProvider::from_str("arn:aws:sts::123456789012:saml-provider/idp").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "sts""#);
+
+        let err = SamlProvider::from_str("arn:aws:iam:us-east-1:123456789012:saml-provider/idp").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -0,0 +1,207 @@
+use {
+    crate::{
+        utils::{split_iam_resource, validate_name, validate_path},
+        PrincipalError,
+    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        str::FromStr,
+    },
+};
+
+/// Details about an AWS IAM server certificate.
+///
+/// ServerCertificate structs are immutable.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ServerCertificate {
+    /// The partition this server certificate exists in.
+    partition: String,
+
+    /// The account id.
+    account_id: String,
+
+    /// Path, starting with a `/`.
+    path: String,
+
+    /// Name of the server certificate, case-insensitive.
+    server_certificate_name: String,
+}
+
+impl ServerCertificate {
+    /// Create a [ServerCertificate] object.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The partition this server certificate exists in. This must meet the rules of the
+    ///   [ValidationProfile] or a [PrincipalError::InvalidPartition] error will be returned.
+    /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
+    /// * `path`: The IAM path the server certificate is under. This must meet the requirements of [validate_path] or a
+    ///   [PrincipalError::InvalidPath] error will be returned.
+    /// * `server_certificate_name`: The name of the server certificate. This must meet the following requirements or a
+    ///   [PrincipalError::InvalidServerCertificateName] error will be returned:
+    ///     *   The name must contain between 1 and 128 characters.
+    ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
+    ///
+    /// # Return value
+    ///
+    /// If all of the requirements are met, a [ServerCertificate] object is returned. Otherwise, a [PrincipalError]
+    /// error is returned.
+    pub fn new(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        server_certificate_name: &str,
+    ) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(partition, account_id, path, server_certificate_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [ServerCertificate] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [ServerCertificate::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        server_certificate_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
+        validate_path(path)?;
+        validate_name(server_certificate_name, 128, PrincipalError::InvalidServerCertificateName)?;
+
+        Ok(Self {
+            partition: partition.into(),
+            account_id: account_id.into(),
+            path: path.into(),
+            server_certificate_name: server_certificate_name.into(),
+        })
+    }
+
+    /// The partition of the server certificate.
+    #[inline]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// The account ID of the server certificate.
+    #[inline]
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The path of the server certificate.
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The name of the server certificate.
+    #[inline]
+    pub fn server_certificate_name(&self) -> &str {
+        &self.server_certificate_name
+    }
+}
+
+impl From<&ServerCertificate> for Arn {
+    fn from(certificate: &ServerCertificate) -> Arn {
+        // Safety: The partition and account id were validated when the server certificate was created.
+        unsafe {
+            Arn::new_unchecked(
+                &certificate.partition,
+                "iam",
+                "",
+                &certificate.account_id,
+                &format!("server-certificate{}{}", certificate.path, certificate.server_certificate_name),
+            )
+        }
+    }
+}
+
+impl FromStr for ServerCertificate {
+    type Err = PrincipalError;
+
+    /// Parse an ARN, returning a [ServerCertificate] if the ARN is a valid server certificate ARN.
+    fn from_str(arn: &str) -> Result<Self, PrincipalError> {
+        let parsed_arn = Arn::from_str(arn)?;
+        Self::try_from(&parsed_arn)
+    }
+}
+
+impl TryFrom<&Arn> for ServerCertificate {
+    type Error = PrincipalError;
+
+    /// If an [Arn] represents a valid IAM server certificate, convert it to a [ServerCertificate]; otherwise, return a
+    /// [PrincipalError] indicating what is wrong with the ARN.
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let (path, server_certificate_name) = split_iam_resource(arn, "server-certificate")?;
+        Self::new(arn.partition(), arn.account_id(), path, server_certificate_name)
+    }
+}
+
+impl Display for ServerCertificate {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "arn:{}:iam::{}:server-certificate{}{}",
+            self.partition, self.account_id, self.path, self.server_certificate_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::ServerCertificate, scratchstack_arn::Arn, std::str::FromStr};
+
+    #[test]
+    fn check_components() {
+        let certificate = ServerCertificate::new("aws", "123456789012", "/my/path/", "cert-name").unwrap();
+        assert_eq!(certificate.partition(), "aws");
+        assert_eq!(certificate.account_id(), "123456789012");
+        assert_eq!(certificate.path(), "/my/path/");
+        assert_eq!(certificate.server_certificate_name(), "cert-name");
+        assert_eq!(certificate.to_string(), "arn:aws:iam::123456789012:server-certificate/my/path/cert-name");
+
+        let arn: Arn = (&certificate).into();
+        assert_eq!(arn.to_string(), certificate.to_string());
+        assert_eq!(ServerCertificate::try_from(&arn).unwrap(), certificate);
+        assert_eq!(
+            ServerCertificate::from_str("arn:aws:iam::123456789012:server-certificate/cert-name").unwrap().path(),
+            "/"
+        );
+    }
+
+    #[test]
+    fn check_invalid() {
+        let err = ServerCertificate::new("aws", "123456789012", "/", "bad!name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid server certificate name: "bad!name""#);
+
+        let long_name = "n".repeat(129);
+        let err = ServerCertificate::new("aws", "123456789012", "/", &long_name).unwrap_err();
+        assert_eq!(err.to_string(), format!(r#"Invalid server certificate name: "{long_name}""#));
+        ServerCertificate::new("aws", "123456789012", "/", &long_name[1..]).unwrap();
+
+        let err = ServerCertificate::new("aws", "123456789012", "/path", "cert-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid path: "/path""#);
+
+        let err = ServerCertificate::from_str("arn:aws:iam::123456789012:server-certificate/").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid server certificate name: """#);
+
+        let err = ServerCertificate::from_str("arn:aws:iam::123456789012:server-certificate").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "server-certificate""#);
+
+        let err = ServerCertificate::from_str("arn:aws:iam::123456789012:user/cert-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid resource: "user/cert-name""#);
+
+        let err = ServerCertificate::from_str("arn:aws:s3::123456789012:server-certificate/cert-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid service name: "s3""#);
+
+        let err =
+            ServerCertificate::from_str("arn:aws:iam:us-east-1:123456789012:server-certificate/cert-name").unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "us-east-1""#);
+    }
+}
+// end tests -- do not delete; needed for coverage.
@@ -1,6 +1,6 @@
 use {
     crate::{utils::validate_dns, PrincipalError},
-    scratchstack_arn::utils::validate_region,
+    scratchstack_arn::{ScratchstackRelaxed, ValidationProfile},
     std::fmt::{Display, Formatter, Result as FmtResult},
 };
 
@@ -25,7 +25,7 @@ impl Service {
     /// # Arguments
     ///
     /// * `service_name`: The name of the service. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidService] error will be returned:
+    ///   [PrincipalError::InvalidService] error will be returned:
     ///     *   The name must contain between 1 and 32 characters.
     ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
     /// * `region`: The region the service is running in. If `None`, the service is global.
@@ -45,13 +45,26 @@ impl Service {
     /// assert_eq!(service.global_dns_name(), "s3.amazonaws.com");
     /// ```
     pub fn new(service_name: &str, region: Option<String>, dns_suffix: &str) -> Result<Self, PrincipalError> {
+        Self::new_with_profile(service_name, region, dns_suffix, &ScratchstackRelaxed)
+    }
+
+    /// Create a [Service] object, validating the region according to the specified [ValidationProfile] instead of
+    /// the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [Service::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        service_name: &str,
+        region: Option<String>,
+        dns_suffix: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
         validate_dns(service_name, 32, PrincipalError::InvalidService)?;
         validate_dns(dns_suffix, 128, PrincipalError::InvalidService)?;
 
         let region = match region {
             None => None,
             Some(region) => {
-                validate_region(region.as_str())?;
+                profile.validate_region(region.as_str())?;
                 Some(region)
             }
         };
@@ -109,6 +122,7 @@ mod tests {
     use {
         super::Service,
         crate::{PrincipalIdentity, PrincipalSource},
+        scratchstack_arn::AwsStrict,
         std::{
             collections::hash_map::DefaultHasher,
             hash::{Hash, Hasher},
@@ -134,6 +148,14 @@ mod tests {
         assert_eq!(source.to_string(), "Service".to_string());
     }
 
+    #[test]
+    fn check_profiles() {
+        Service::new("s3", Some("local".into()), "amazonaws.com").unwrap();
+        Service::new_with_profile("s3", Some("us-east-1".into()), "amazonaws.com", &AwsStrict).unwrap();
+        let err = Service::new_with_profile("s3", Some("local".into()), "amazonaws.com", &AwsStrict).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid region: "local""#);
+    }
+
     #[test]
     fn check_derived() {
         let s1a = Service::new("s3", None, "amazonaws.com").unwrap();
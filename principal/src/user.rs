@@ -3,10 +3,7 @@ use {
         utils::{validate_name, validate_path},
         PrincipalError,
     },
-    scratchstack_arn::{
-        utils::{validate_account_id, validate_partition},
-        Arn,
-    },
+    scratchstack_arn::{Arn, ScratchstackRelaxed, ValidationProfile},
     std::{
         fmt::{Display, Formatter, Result as FmtResult},
         str::FromStr,
@@ -37,15 +34,15 @@ impl User {
     /// # Arguments
     ///
     /// * `account_id`: The 12 digit account id. This must be composed of 12 ASCII digits or a
-    ///     [PrincipalError::InvalidAccountId] error will be returned.
+    ///   [PrincipalError::InvalidAccountId] error will be returned.
     /// * `path`: The IAM path the group is under. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidPath] error will be returned:
+    ///   [PrincipalError::InvalidPath] error will be returned:
     ///     *   The path must contain between 1 and 512 characters.
     ///     *   The path must start and end with `/`.
     ///     *   All characters in the path must be in the ASCII range 0x21 (`!`) through 0x7E (`~`). The AWS documentation
     ///         erroneously indicates that 0x7F (DEL) is acceptable; however, the IAM APIs reject this character.
     /// * `user_name`: The name of the user. This must meet the following requirements or a
-    ///     [PrincipalError::InvalidUserName] error will be returned:
+    ///   [PrincipalError::InvalidUserName] error will be returned:
     ///     *   The name must contain between 1 and 64 characters.
     ///     *   The name must be composed to ASCII alphanumeric characters or one of `, - . = @ _`.
     ///
@@ -54,8 +51,22 @@ impl User {
     /// If all of the requirements are met, a [User] object is returned. Otherwise, a [PrincipalError] error
     /// is returned.
     pub fn new(partition: &str, account_id: &str, path: &str, user_name: &str) -> Result<Self, PrincipalError> {
-        validate_partition(partition)?;
-        validate_account_id(account_id)?;
+        Self::new_with_profile(partition, account_id, path, user_name, &ScratchstackRelaxed)
+    }
+
+    /// Create a [User] object, validating the partition and account id according to the specified
+    /// [ValidationProfile] instead of the default [ScratchstackRelaxed] profile.
+    ///
+    /// The arguments and other requirements are the same as for [User::new].
+    pub fn new_with_profile<P: ValidationProfile + ?Sized>(
+        partition: &str,
+        account_id: &str,
+        path: &str,
+        user_name: &str,
+        profile: &P,
+    ) -> Result<Self, PrincipalError> {
+        profile.validate_partition(partition)?;
+        profile.validate_account_id(account_id)?;
         validate_path(path)?;
         validate_name(user_name, 64, PrincipalError::InvalidUserName)?;
 
@@ -94,7 +105,16 @@ impl User {
 
 impl From<&User> for Arn {
     fn from(user: &User) -> Arn {
-        Arn::new(&user.partition, "iam", "", &user.account_id, &format!("user{}{}", user.path, user.user_name)).unwrap()
+        // Safety: The partition and account id were validated when the user was created.
+        unsafe {
+            Arn::new_unchecked(
+                &user.partition,
+                "iam",
+                "",
+                &user.account_id,
+                &format!("user{}{}", user.path, user.user_name),
+            )
+        }
     }
 }
 
@@ -173,7 +193,7 @@ mod tests {
     use {
         super::User,
         crate::{PrincipalIdentity, PrincipalSource},
-        scratchstack_arn::Arn,
+        scratchstack_arn::{utils::validate_account_id_digits, Arn, ArnError, AwsStrict, ValidationProfile},
         std::{
             collections::hash_map::DefaultHasher,
             hash::{Hash, Hasher},
@@ -288,6 +308,30 @@ mod tests {
         let _ = format!("{u3:?}");
     }
 
+    struct OnPrem;
+
+    impl ValidationProfile for OnPrem {
+        fn validate_account_id(&self, account_id: &str) -> Result<(), ArnError> {
+            validate_account_id_digits(account_id, 10)
+        }
+    }
+
+    #[test]
+    fn check_profiles() {
+        let user = User::new_with_profile("aws", "1234567890", "/", "user-name", &OnPrem).unwrap();
+        assert_eq!(user.account_id(), "1234567890");
+        let arn: Arn = (&user).into();
+        assert_eq!(arn.to_string(), "arn:aws:iam::1234567890:user/user-name");
+
+        let err = User::new_with_profile("aws", "123456789012", "/", "user-name", &OnPrem).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid account id: "123456789012""#);
+
+        User::new_with_profile("aws-cn", "123456789012", "/", "user-name", &AwsStrict).unwrap();
+        let err = User::new_with_profile("aws-中国", "123456789012", "/", "user-name", &AwsStrict).unwrap_err();
+        assert_eq!(err.to_string(), r#"Invalid partition: "aws-中国""#);
+        User::new("aws-中国", "123456789012", "/", "user-name").unwrap();
+    }
+
     #[test]
     fn check_invalid_users() {
         let err = User::new("", "123456789012", "/", "user-name").unwrap_err();
@@ -1,5 +1,6 @@
 use {
     crate::PrincipalError,
+    scratchstack_arn::Arn,
     std::fmt::{Display, Formatter, Result as FmtResult},
 };
 
@@ -191,6 +192,42 @@ pub fn validate_path(path: &str) -> Result<(), PrincipalError> {
     Ok(())
 }
 
+/// Strip the resource type from the resource of an IAM ARN in the form `resource_type/rest`, returning `rest`.
+///
+/// If `arn` is not for the `iam` service, has a region, or does not have the specified resource type, an
+/// appropriate [PrincipalError] is returned. The rest of the resource is not validated.
+pub(crate) fn strip_iam_resource_type<'a>(arn: &'a Arn, resource_type: &str) -> Result<&'a str, PrincipalError> {
+    let service = arn.service();
+    let region = arn.region();
+    let resource = arn.resource();
+
+    if service != "iam" {
+        return Err(PrincipalError::InvalidService(service.to_string()));
+    }
+
+    if !region.is_empty() {
+        return Err(PrincipalError::InvalidRegion(region.to_string()));
+    }
+
+    resource
+        .strip_prefix(resource_type)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .ok_or_else(|| PrincipalError::InvalidResource(resource.to_string()))
+}
+
+/// Split the resource of an IAM ARN in the form `resource_type/path/name` into its path (including the leading and
+/// trailing `/`) and name.
+///
+/// The ARN is checked as by [strip_iam_resource_type]. The path and name are not validated.
+pub(crate) fn split_iam_resource<'a>(arn: &'a Arn, resource_type: &str) -> Result<(&'a str, &'a str), PrincipalError> {
+    strip_iam_resource_type(arn, resource_type)?;
+
+    // Keep the `/` after the resource type as the start of the path.
+    let path_and_name = &arn.resource()[resource_type.len()..];
+    let last_slash = path_and_name.rfind('/').unwrap(); // Safe because we know the string starts with "/".
+    Ok((&path_and_name[..=last_slash], &path_and_name[last_slash + 1..]))
+}
+
 /// Verify that a DNS name meets Scratchstack requirements.
 ///
 /// DNS names may have multiple components separated by a dot (`.`). Each component must be between 1 and 63 characters.